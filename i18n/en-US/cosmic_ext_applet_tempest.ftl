@@ -1,52 +1,269 @@
 app-name = Tempest Weather
 loading = Loading weather data...
+loading-short = Loading weather…
+weather-unavailable = Weather unavailable
+panel-tooltip = { $condition }, { $temp }. Humidity { $humidity }%. Wind { $wind }.
+weather-summary-copy = Current weather in { $location }: { $temp }, { $condition }. Humidity { $humidity }%, Wind { $wind }, UV { $uv }. Updated { $time }.
+copied = Copied!
 updated = Updated: { $time }
+refreshing = Refreshing…
+time-just-now = just now
+time-minutes-ago = { $minutes } min ago
+time-hours-ago = { $hours } hr ago
 retry = Retry
+offline = No internet connection
+data-stale = Data is { $minutes } minutes old
+data-stale-offline = Data is { $minutes } minutes old — network unavailable
+data-stale-error = Data is { $minutes } minutes old — the last refresh failed
+retry-aqi = Retry AQI
+aqi-failed = Failed to load air quality
+alerts-failed = Failed to load weather alerts
+error-network = Unable to reach the weather service. Check your connection.
+error-timeout = The weather service took too long to respond.
+error-rate-limited = Too many requests to the weather service. Try again later.
 failed-to-load = Failed to load weather
 aqi-label = AQI { $value }
 tab-current = Current
 tab-hourly = Hourly
-tab-forecast = 7-Day
+tab-forecast = { $days }-Day
 tab-air-quality = Air
+tab-precipitation = Rain
+tab-astronomy = Sky
+tab-solar = Solar
+tab-marine = Marine
+tab-agricultural = Garden
 feels-like = Feels like: { $temp }
+apparent-temp = Apparent temp: { $temp }
+heat-index = Heat index: { $temp }
+wind-chill = Wind chill: { $temp }
 humidity = Humidity: { $value }%
+dew-point = Dew point: { $temp }
 wind = Wind: { $speed } { $unit } { $direction }
+wind-beaufort = { $wind } (Force { $force }: { $description })
+beaufort-calm = Calm
+beaufort-light-air = Light air
+beaufort-light-breeze = Light breeze
+beaufort-gentle-breeze = Gentle breeze
+beaufort-moderate-breeze = Moderate breeze
+beaufort-fresh-breeze = Fresh breeze
+beaufort-strong-breeze = Strong breeze
+beaufort-near-gale = Near gale
+beaufort-gale = Gale
+beaufort-strong-gale = Strong gale
+beaufort-storm = Storm
+beaufort-violent-storm = Violent storm
+beaufort-hurricane = Hurricane force
 gusts = Gusts: { $speed } { $unit }
-uv-index = UV Index: { $value }
+precipitation = Precipitation: { $value } { $unit }
+precipitation-total = Total expected today: { $value }
+uv-index = UV Index: { $value } ({ $category })
+uv-low = Low
+uv-moderate = Moderate
+uv-high = High
+uv-very-high = Very High
+uv-extreme = Extreme
 cloud-cover = Cloud Cover: { $value }%
+fire-danger = Fire Danger: { $level }
+fire-danger-low = Low
+fire-danger-moderate = Moderate
+fire-danger-high = High
+fire-danger-very-high = Very High
+fire-danger-extreme = Extreme
+lightning-risk = Lightning Risk: { $level } (CAPE { $cape } J/kg)
+lightning-none = None
+lightning-low = Low
+lightning-moderate = Moderate
+lightning-high = High
 visibility = Visibility: { $value } { $unit }
-pressure = Pressure: { $value } hPa
+visibility-fog = Visibility: { $value } { $unit } (Fog likely)
+pressure = Pressure: { $value } { $unit }
+pressure-sea-level = Pressure: { $value } { $unit } (SL: { $sea_level } { $unit })
 sunrise = Sunrise: { $time }
 sunset = Sunset: { $time }
+solar-noon = Solar noon: { $time }
+civil-dawn = Civil dawn: { $time }
+civil-dusk = Civil dusk: { $time }
+moon-phase = Moon: { $name } ({ $illumination }% illuminated)
+moon-new = New Moon
+moon-waxing-crescent = Waxing Crescent
+moon-first-quarter = First Quarter
+moon-waxing-gibbous = Waxing Gibbous
+moon-full = Full Moon
+moon-waning-gibbous = Waning Gibbous
+moon-last-quarter = Last Quarter
+moon-waning-crescent = Waning Crescent
+comfort = { $emoji } Comfort: { $score }/100 ({ $label })
+comfort-excellent = Excellent
+comfort-good = Good
+comfort-fair = Fair
+comfort-uncomfortable = Uncomfortable
+comfort-very-uncomfortable = Very Uncomfortable
 air-quality-unavailable = Air quality data unavailable
 pm25 = PM2.5: { $value } ug/m3
 pm10 = PM10: { $value } ug/m3
 ozone = Ozone: { $value } ug/m3
 no2 = NO2: { $value } ug/m3
 co = CO: { $value } ug/m3
+pollen = Pollen
+pollen-value = { $name }: { $value } grains/m3
+pollen-alder = Alder
+pollen-birch = Birch
+pollen-grass = Grass
+pollen-mugwort = Mugwort
+pollen-olive = Olive
+pollen-ragweed = Ragweed
+aqi-standard-us = US AQI
+aqi-standard-eu = EU AQI
+aqi-standard-ca = AQHI
+aqi-good = Good
+aqi-fair = Fair
+aqi-moderate = Moderate
+aqi-poor = Poor
+aqi-very-poor = Very Poor
+aqi-extremely-poor = Extremely Poor
+aqi-unhealthy-sensitive = Unhealthy for Sensitive Groups
+aqi-unhealthy = Unhealthy
+aqi-very-unhealthy = Very Unhealthy
+aqi-hazardous = Hazardous
+aqhi-low = Low (1-3)
+aqhi-moderate = Moderate (4-6)
+aqhi-high = High (7-10)
+aqhi-very-high = Very High (10+)
+aqi-peak = Peak AQI today: { $value } at { $time }
+solar-generation = Estimated today: { $value } kWh
+solar-details = { $kw } kW system, { $hours } peak sun hours
+solar-split = { $direct } direct / { $diffuse } diffuse
+solar-unavailable = Solar forecast unavailable
+marine-waves = Waves: { $height } { $unit } from { $direction }
+marine-period = Wave period: { $seconds } s
+marine-swell = Swell: { $height } { $unit } from { $direction }
+marine-wind-waves = Wind waves: { $height } { $unit }
+marine-unavailable = No marine data for this location
+agricultural-water-yes = Water today: yes (evaporation exceeds rainfall)
+agricultural-water-no = Water today: no (rainfall covers evaporation)
+agricultural-soil = Soil
 alerts-disabled = Weather alerts are disabled
 alerts-enable-hint = Enable them in Settings
 no-active-alerts = No active alerts
 area-clear = Your area is clear
-expires = Expires: { $time }
+expires-countdown = Expires: { $time } (in { $remaining })
+active-from = Starts: { $time }
+alert-certainty = Certainty: { $value }
+alert-action = Action:
+alert-history = History ({ $count })
+alert-expired-ago = Expired { $time } ago
+alert-ended = Ended
 forecast-day = Day
 forecast-high = High
 forecast-low = Low
+forecast-rain-chance = Rain%
+forecast-precip = Precip
+forecast-uv = UV
+forecast-et0 = ET₀
 forecast-conditions = Conditions
+forecast-extended-note = 14-day forecasts are less accurate
+forecast-plan-limit = WeatherAPI.com returned { $days } of { $requested } days; your plan limits the forecast length
+forecast-feels-like = { $temp } (feels { $feels })
 settings-temperature-unit = Temperature Unit
+settings-temperature-decimal = Show decimal in temperature
 settings-auto-units = Auto-select Units
 settings-auto-units-hint = Based on location
 settings-auto-location = Auto-detect Location
 settings-detect-now = Detect Now
 settings-current-location = Current Location
+settings-open-in-maps = Open in Maps
+settings-save-location = Save Location
+settings-saved-locations = Saved Locations
 settings-search-location = Search Location
+settings-live-search = Search While Typing
 settings-search-placeholder = Enter city name...
 settings-search = Search
+settings-coordinates = Latitude / Longitude
+settings-set-coordinates = Set Coordinates
 settings-refresh-interval = Refresh Interval
 settings-minutes = minutes
 settings-weather-alerts = Weather Alerts
-settings-alerts-hint = US, Canada & Europe
+settings-alerts-hint = US, Canada, Europe, Australia & India
+settings-notify-severity = Notify For
+settings-notify-all = All alerts
+settings-notify-moderate = Moderate+
+settings-notify-severe = Severe+
+settings-notify-extreme = Extreme only
 settings-show-aqi = Show AQI in Panel
+settings-show-temperature = Show Temperature in Panel
+settings-show-wind = Show Wind in Panel
+settings-show-precip = Show Rain Chance in Panel
+settings-panel-format = Panel Format
+settings-panel-format-hint = Placeholders: {"{"}temp{"}"} {"{"}aqi{"}"} {"{"}wind{"}"} {"{"}precip{"}"} {"{"}precip_amount{"}"} {"{"}humidity{"}"} {"{"}condition{"}"}
+settings-panel-preview = Preview
+settings-panel-tooltip = Show Panel Tooltip
+settings-panel-rotate = Rotate Panel Metrics
+settings-hourly-forecast = Hourly Forecast
+settings-hours = { $count } hours
+settings-daily-forecast = Daily Forecast
+settings-wind-unit = Wind Speed Unit
+settings-pressure-unit = Pressure Unit
+settings-time-format = Time Format
+settings-time-format-auto = Auto ({ $format })
+time-format-auto = Auto
+time-format-12-hour = 12-hour
+time-format-24-hour = 24-hour
+settings-wind-unit-auto = Auto ({ $unit })
+settings-show-hourly-humidity = Show Hourly Humidity
+settings-show-daily-feels-like = Show Feels-like in Forecast
+settings-show-moon-phase = Show Moon Phase
+settings-show-beaufort = Show Beaufort Scale
+settings-show-fire-weather = Show Fire Danger
+settings-show-convective = Show Lightning Risk
+settings-marine = Show Marine Tab
+settings-show-agricultural = Show Agricultural Data
+settings-pollen = Show Pollen Levels
+settings-show-solar = Show Solar Tab
+settings-solar-panel-size = Solar System Size
+settings-show-weather-photo = Show Weather Photo
+settings-unsplash-key = Unsplash Access Key
+settings-api-key = Open-Meteo API Key
+settings-api-key-hint = Only needed for the commercial tier
+settings-advanced = Advanced
+settings-api-url = Custom API URL
+settings-api-url-invalid = Enter a valid http:// or https:// URL
+settings-weather-provider = Weather Provider
+settings-weatherapi-key = WeatherAPI.com Key
+settings-startup-tab = Startup Tab
+settings-popup-width = Popup Width
+settings-popup-max-height = Popup Max Height
+settings-pixels = px
+settings-startup-tab-last-used = Last Used
 settings-version = Version
 settings-support = Support
 settings-tip-kofi = Tip me on Ko-fi
+settings-whats-new = What's new in v{ $version }
+settings-export = Export Settings
+settings-import = Import Settings
+settings-exported = Settings exported to { $path }
+settings-export-failed = Export failed: { $error }
+settings-imported = Settings imported
+settings-import-failed = Import failed: { $error }
+settings-export-include-keys = Include API Keys in Export
+config-not-json = Not a settings file: { $error }
+config-not-settings-file = Not a Tempest settings file
+config-newer-version = Settings were exported by a newer version (config v{ $version }, this is v{ $current })
+config-invalid = Invalid settings: { $error }
+config-invalid-coordinates = Latitude must be between -90 and 90 and longitude between -180 and 180
+config-invalid-refresh-interval = Refresh interval must be between 1 and 1440 minutes
+config-invalid-popup-width = Popup width must be between 380 and 700 pixels
+config-invalid-popup-height = Popup height must be between 400 and 900 pixels
+config-invalid-retry-attempts = Retry attempts must be between 1 and 10
+config-invalid-decimal-places = Temperature decimal places must be 0 or 1
+config-invalid-geofence = Geofence distance must be between 0 and 1000 km
+settings-clear-cache = Clear Cache & Refresh
+settings-clearing-cache = Clearing…
+
+# Onboarding
+onboarding-welcome = Welcome to Tempest
+onboarding-hint = Choose a location to see its weather.
+onboarding-detect = Auto-detect my location
+onboarding-dismiss = Keep the default location
+location-detect-failed = Couldn't detect your location. Try searching for a city instead.
+search-no-results = No matching places found
+search-failed = Search failed. Check your connection and try again.
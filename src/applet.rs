@@ -1,5 +1,6 @@
 // SPDX-License-Identifier: GPL-3.0-only
 
+use chrono::{DateTime, Utc};
 use cosmic::app::{Core, Task};
 use cosmic::cosmic_config::CosmicConfigEntry;
 use cosmic::iced::platform_specific::shell::wayland::commands::popup::{destroy_popup, get_popup};
@@ -8,19 +9,85 @@ use cosmic::iced::{Limits, Subscription};
 use cosmic::iced_futures::Subscription as IcedSubscription;
 use cosmic::widget::{self, settings, text};
 use cosmic::{Action, Application, Element};
-use std::collections::HashSet;
+use std::collections::{HashSet, VecDeque};
+use std::sync::Arc;
 use std::time::Duration;
 
-use crate::config::{Config, MeasurementSystem, PopupTab, TemperatureUnit};
+use crate::cache;
+use crate::config::{
+    Config, ConfigExport, ConfigPatch, MeasurementSystem, PopupTab, SavedLocation, SeenAlert,
+    TemperatureUnit, TimeFormat, WeatherProvider, WindSpeedUnit,
+};
+use crate::photo_cache;
+use crate::weather::providers::weatherapi;
 use crate::weather::{
-    aqi_standard_label, aqi_to_description, detect_location, fetch_air_quality, fetch_alerts,
-    fetch_weather, format_date, format_hour, format_time, is_night_time, search_city,
-    uses_imperial_units, weathercode_to_description, weathercode_to_icon_name,
-    wind_direction_to_compass, AirQualityData, Alert, AlertSeverity, AqiStandard, LocationResult,
-    WeatherData,
+    aqi_color, aqi_standard_label, aqi_to_description, beaufort_to_description, check_connectivity,
+    comfort_emoji, comfort_index, comfort_label, detect_country_from_coords, detect_location,
+    estimate_solar_generation, fetch_air_quality, fetch_alerts, fetch_marine, fetch_pollen,
+    fetch_solar_forecast, fetch_weather, find_peak_aqi_hour, fire_weather_index, fog_risk,
+    format_date, format_date_time, format_duration_since, format_duration_until, format_hour,
+    format_time, haversine_distance, heat_index, init_http_client, is_night_time,
+    lightning_risk_label, moon_illumination, moon_phase_fraction, moon_phase_name, ms_to_beaufort,
+    needs_watering, parse_local_timestamp, reverse_geocode_city, search_city,
+    set_max_retry_attempts, shift_time, to_beaufort, to_sea_level_pressure, uses_imperial_units,
+    uv_index_to_category, validate_coordinates, weathercode_to_description,
+    weathercode_to_icon_name, wind_chill, wind_direction_to_arrow, wind_direction_to_compass,
+    AirQualityData, Alert, AlertSeverity, AqiStandard, Conditional, CurrentWeather, HourlyForecast,
+    LocationResult, MarineData, OpenMeteoApi, PollenData, SolarForecastData, WeatherData,
+    WeatherError, CIVIL_TWILIGHT_MINUTES,
 };
 
 const VERSION: &str = env!("CARGO_PKG_VERSION");
+const CHANGELOG: &str = include_str!("../CHANGELOG.md");
+
+/// Number of pressure readings kept for the trend, one per refresh.
+const PRESSURE_HISTORY_LEN: usize = 12;
+
+/// How often auto-location checks whether the user has moved, independent of refreshes.
+const LOCATION_REDETECT_INTERVAL: Duration = Duration::from_secs(30 * 60);
+
+/// How long typing must pause before a live city search fires.
+const SEARCH_DEBOUNCE: Duration = Duration::from_millis(600);
+
+/// Settings a weather fetch was started with. A refresh queued behind an
+/// in-flight fetch only runs if these changed by the time it returns.
+#[derive(Debug, Clone, PartialEq)]
+struct FetchSettings {
+    latitude: f64,
+    longitude: f64,
+    temperature_unit: TemperatureUnit,
+    wind_speed_unit: WindSpeedUnit,
+    hourly_hours: u8,
+    forecast_days: u8,
+    provider: WeatherProvider,
+    weatherapi_key: Option<String>,
+    alerts_enabled: bool,
+    show_solar_tab: bool,
+    pollen_enabled: bool,
+    marine_enabled: bool,
+    open_meteo: OpenMeteoApi,
+}
+
+impl FetchSettings {
+    /// Cache key for data fetched with these settings.
+    fn cache_key(&self) -> cache::CacheKey {
+        cache::CacheKey {
+            latitude: self.latitude,
+            longitude: self.longitude,
+            temperature_unit: self.temperature_unit,
+            wind_speed_unit: self.wind_speed_unit,
+        }
+    }
+}
+
+/// Startup tab choices in dropdown order. None means "remember last used".
+const STARTUP_TABS: [Option<PopupTab>; 5] = [
+    None,
+    Some(PopupTab::Current),
+    Some(PopupTab::Hourly),
+    Some(PopupTab::Forecast),
+    Some(PopupTab::AirQuality),
+];
 
 /// This is the struct that represents your application.
 /// It is used to define the data that will be used by your application.
@@ -35,7 +102,16 @@ pub struct Tempest {
     air_quality: Option<AirQualityData>,
     /// Active weather alerts.
     alerts: Vec<Alert>,
+    /// Weather photo banner, fetched only when enabled and an Unsplash key is set.
+    weather_photo: Option<widget::image::Handle>,
+    /// Date and condition the current photo was requested for, so it changes daily.
+    weather_photo_key: Option<String>,
+    /// Solar irradiance forecast, fetched only when the Solar tab is enabled.
+    solar_forecast: Option<SolarForecastData>,
+    pollen_data: Option<PollenData>,
+    marine_data: Option<MarineData>,
     /// IDs of alerts already shown as notifications (prevents duplicates).
+    /// Persisted with expiry times in `config.seen_alert_ids`.
     seen_alert_ids: HashSet<String>,
     /// Configuration
     config: Config,
@@ -44,22 +120,85 @@ pub struct Tempest {
     /// Input field states
     city_input: String,
     refresh_input: String,
+    popup_width_input: String,
+    popup_height_input: String,
+    solar_panel_input: String,
+    unsplash_key_input: String,
+    api_key_input: String,
+    /// Whether the Open-Meteo API key is shown in plain text
+    show_api_key: bool,
+    /// Custom Open-Meteo URL field and its validation error
+    api_url_input: String,
+    api_url_error: Option<String>,
+    weatherapi_key_input: String,
+    /// Whether the Advanced settings section is expanded
+    show_advanced_settings: bool,
+    /// Result of the last settings export or import
+    config_transfer_status: Option<String>,
+    /// Export API keys along with the other settings
+    export_include_keys: bool,
+    /// Why the last location detection or city search failed
+    location_error: Option<String>,
+    /// Whether the changelog is expanded in the About section
+    show_changelog: bool,
+    /// Cache was cleared and the refresh hasn't answered yet
+    clearing_cache: bool,
+    /// Briefly true after the weather summary is copied, to show "Copied!"
+    copied_flash: bool,
     /// Search results
     search_results: Vec<LocationResult>,
+    /// Manual coordinate entry fields and their validation error
+    latitude_input: String,
+    longitude_input: String,
+    coordinates_error: Option<String>,
+    /// Country of the current location, if known (used when saving it)
+    location_country: Option<String>,
     /// Display label for panel button
     display_label: String,
     /// Current weather code for icon display
     current_weathercode: i32,
     /// Current AQI for panel display
     current_aqi: Option<(i32, AqiStandard)>,
-    /// Loading state
-    is_loading: bool,
+    /// Which panel metric is showing while rotation is enabled
+    rotate_panel_index: usize,
+    /// Recent sea-level pressure readings (hPa), oldest first
+    pressure_history: VecDeque<(chrono::DateTime<chrono::Local>, f32)>,
+    /// Coordinates the pressure readings were taken at
+    pressure_history_coords: Option<(f64, f64)>,
+    /// Per-fetch loading states, so each tab shows its own spinner
+    weather_loading: bool,
+    aqi_loading: bool,
+    alerts_loading: bool,
+    /// A weather fetch is running; further refreshes are skipped until it returns
+    fetch_in_flight: bool,
+    /// A refresh was skipped while in flight and should run once it returns
+    refresh_queued: bool,
+    /// Settings the in-flight fetch was started with
+    in_flight_settings: Option<FetchSettings>,
+    /// The last connectivity probe failed
+    is_offline: bool,
+    /// ETags of the last forecast and air quality responses, for conditional requests
+    last_weather_etag: Option<String>,
+    last_aqi_etag: Option<String>,
     /// Error state
     error_message: Option<String>,
+    /// Air quality fetch error, shown on the Air Quality tab only
+    aqi_error: Option<String>,
+    /// Alerts that were active within the last 24 hours, with when each was last
+    /// reported (not persisted)
+    alert_history: Vec<(Alert, DateTime<Utc>)>,
+    /// Whether the alert history section is expanded
+    show_alert_history: bool,
+    /// Alerts fetch error, shown on the Alerts tab only
+    alerts_error: Option<String>,
     /// Active tab in the popup
     active_tab: PopupTab,
-    /// Cached formatted timestamp for display (avoids recomputing on every render)
+    /// Cached relative timestamp for display ("5 min ago"), refreshed every minute
     last_updated_display: Option<String>,
+    /// Render the current conditions card directly instead of a panel button and popup
+    widget_mode: bool,
+    /// Localized labels for the startup tab dropdown, matching `STARTUP_TABS`
+    startup_tab_labels: Vec<String>,
 }
 
 impl Default for Tempest {
@@ -71,17 +210,65 @@ impl Default for Tempest {
             weather_data: None,
             air_quality: None,
             alerts: Vec::new(),
+            weather_photo: None,
+            weather_photo_key: None,
+            solar_forecast: None,
+            pollen_data: None,
+            marine_data: None,
             seen_alert_ids: HashSet::new(),
             city_input: String::new(),
             refresh_input: config.refresh_interval_minutes.to_string(),
+            popup_width_input: config.popup_width.to_string(),
+            popup_height_input: config.popup_max_height.to_string(),
+            solar_panel_input: config.solar_panel_kw.to_string(),
+            unsplash_key_input: config.unsplash_access_key.clone().unwrap_or_default(),
+            api_key_input: config.open_meteo_api_key.clone().unwrap_or_default(),
+            show_api_key: false,
+            api_url_input: config.open_meteo_base_url.clone().unwrap_or_default(),
+            api_url_error: None,
+            weatherapi_key_input: config.weatherapi_key.clone().unwrap_or_default(),
+            show_advanced_settings: false,
+            config_transfer_status: None,
+            export_include_keys: false,
+            location_error: None,
+            show_changelog: false,
+            clearing_cache: false,
+            copied_flash: false,
             search_results: Vec::new(),
+            latitude_input: String::new(),
+            longitude_input: String::new(),
+            coordinates_error: None,
+            location_country: None,
             display_label: "...".to_string(),
             current_weathercode: 0,
             current_aqi: None,
-            is_loading: true,
+            rotate_panel_index: 0,
+            pressure_history: VecDeque::with_capacity(PRESSURE_HISTORY_LEN),
+            pressure_history_coords: None,
+            weather_loading: true,
+            fetch_in_flight: false,
+            refresh_queued: false,
+            in_flight_settings: None,
+            is_offline: false,
+            last_weather_etag: None,
+            last_aqi_etag: None,
+            aqi_loading: false,
+            alerts_loading: false,
             error_message: None,
+            aqi_error: None,
+            alert_history: Vec::new(),
+            show_alert_history: false,
+            alerts_error: None,
             active_tab: PopupTab::default(),
             last_updated_display: None,
+            widget_mode: false,
+            startup_tab_labels: vec![
+                crate::fl!("settings-startup-tab-last-used"),
+                crate::fl!("tab-current"),
+                crate::fl!("tab-hourly"),
+                crate::fl!("settings-daily-forecast"),
+                crate::fl!("tab-air-quality"),
+            ],
             config,
             config_handler: None,
         }
@@ -94,24 +281,93 @@ pub enum Message {
     TogglePopup,
     PopupClosed(Id),
     RefreshWeather,
-    WeatherUpdated(Result<WeatherData, String>),
-    AirQualityUpdated(Result<AirQualityData, String>),
+    ConnectivityChecked(bool),
+    RetryAirQuality,
+    RetryAlerts,
+    UpdateRelativeTimestamp,
+    AlertTimerTick,
+    PanelRotateTick,
+    TogglePanelRotate,
+    ToggleShowWindInPanel,
+    ToggleShowPrecipInPanel,
+    ToggleAlertHistory,
+    WeatherUpdated(Result<Conditional<WeatherData>, Arc<WeatherError>>),
+    AirQualityUpdated(cache::CacheKey, Result<Conditional<AirQualityData>, String>),
     AlertsUpdated(Result<Vec<Alert>, String>),
+    SolarUpdated(Result<SolarForecastData, String>),
+    PollenUpdated(Result<PollenData, String>),
+    MarineUpdated(Result<MarineData, String>),
+    WeatherPhotoLoaded(Result<Vec<u8>, String>),
     Tick,
     ToggleTemperatureUnit,
     ToggleAlertsEnabled,
     ToggleShowAqiInPanel,
+    ToggleShowTemperatureInPanel,
+    ToggleShowSolarTab,
+    TogglePollenEnabled,
+    ToggleMarineEnabled,
+    ToggleShowAgricultural,
+    ToggleShowWeatherPhoto,
+    ToggleShowHourlyHumidity,
+    CycleHourlyForecastHours,
+    CycleForecastDays,
+    CycleWindUnit,
+    CycleNotificationSeverity,
+    CyclePressureUnit,
+    CycleTimeFormat,
+    ToggleChangelog,
+    ClearCache,
+    CopyWeatherSummary,
+    ClearCopiedFlash,
+    ToggleExportIncludeKeys,
+    ExportConfig,
+    ConfigExported(Result<Option<String>, String>),
+    ImportConfig,
+    ConfigImported(Result<Option<Config>, String>),
+    ToggleShowDailyFeelsLike,
+    ToggleTemperatureDecimal,
+    ToggleShowMoonPhase,
+    ToggleShowBeaufort,
+    ToggleShowFireWeather,
+    ToggleShowConvectiveData,
     ToggleAutoUnits,
     UpdateCityInput(String),
     SearchCity,
+    SaveCurrentLocation,
+    SwitchToSavedLocation(usize),
+    RemoveSavedLocation(usize),
+    ToggleLiveSearch,
     CitySearchResult(Result<Vec<LocationResult>, String>),
     SelectLocation(usize),
+    BulkUpdateConfig(ConfigPatch),
+    UpdateLatitudeInput(String),
+    UpdateLongitudeInput(String),
+    /// Sets the location to exact coordinates entered in settings.
+    SetManualCoordinates(f64, f64),
+    CoordinatesGeocoded(Result<(String, String), String>),
     UpdateRefreshInterval(String),
+    UpdatePopupWidth(String),
+    UpdatePopupMaxHeight(String),
+    UpdateSolarPanelKw(String),
+    UpdateUnsplashKey(String),
+    UpdateApiKey(String),
+    ToggleApiKeyVisibility,
+    ToggleAdvancedSettings,
+    UpdateApiUrl(String),
+    CycleWeatherProvider,
+    UpdateWeatherApiKey(String),
+    UpdatePanelFormat(String),
+    ToggleShowPanelTooltip,
     DetectLocation,
+    DismissOnboarding,
     LocationDetected(Result<(f64, f64, String, String), String>),
+    RedetectLocation,
+    LocationRedetected(Result<(f64, f64, String, String), String>),
     ToggleAutoLocation,
     SelectTab(PopupTab),
+    SelectStartupTab(usize),
     OpenUrl(String),
+    OpenInMaps,
 }
 
 /// Implement the `Application` trait for your application.
@@ -125,7 +381,8 @@ pub enum Message {
 impl Application for Tempest {
     type Executor = cosmic::executor::Default;
 
-    type Flags = ();
+    /// Whether to run in widget mode.
+    type Flags = bool;
 
     type Message = Message;
 
@@ -144,9 +401,9 @@ impl Application for Tempest {
     /// Any work that needs to be done before the application starts should be done here.
     ///
     /// - `core` is used to passed on for you by libcosmic to use in the core of your own application.
-    /// - `flags` is used to pass in any data that your application needs to use before it starts.
+    /// - `widget_mode` is the flag passed from `main` selecting widget mode over the panel button.
     /// - `Task` type is used to send messages to your application. `Task::none()` can be used to send no messages to your application.
-    fn init(core: Core, _flags: Self::Flags) -> (Self, Task<Self::Message>) {
+    fn init(core: Core, widget_mode: Self::Flags) -> (Self, Task<Self::Message>) {
         let config_handler = cosmic::cosmic_config::Config::new(Self::APP_ID, Config::VERSION).ok();
         let config = config_handler
             .as_ref()
@@ -154,20 +411,58 @@ impl Application for Tempest {
             .unwrap_or_default();
 
         let refresh_input = config.refresh_interval_minutes.to_string();
-        let active_tab = config.default_tab;
+        let popup_width_input = config.popup_width.to_string();
+        let popup_height_input = config.popup_max_height.to_string();
+        let solar_panel_input = config.solar_panel_kw.to_string();
+        let unsplash_key_input = config.unsplash_access_key.clone().unwrap_or_default();
+        let api_key_input = config.open_meteo_api_key.clone().unwrap_or_default();
+        let api_url_input = config.open_meteo_base_url.clone().unwrap_or_default();
+        let weatherapi_key_input = config.weatherapi_key.clone().unwrap_or_default();
+        let active_tab = config.startup_tab.unwrap_or(config.default_tab);
+        set_max_retry_attempts(config.max_retry_attempts);
+        init_http_client(config.http_timeout_seconds, config.connect_timeout_seconds);
 
-        let app = Tempest {
+        let mut app = Tempest {
             core,
             config: config.clone(),
             config_handler,
             city_input: String::new(),
             refresh_input,
+            popup_width_input,
+            popup_height_input,
+            solar_panel_input,
+            unsplash_key_input,
+            api_key_input,
+            api_url_input,
+            weatherapi_key_input,
             search_results: Vec::new(),
             display_label: "...".to_string(),
             active_tab,
+            widget_mode,
+            seen_alert_ids: config
+                .seen_alert_ids
+                .iter()
+                .map(|seen| seen.id.clone())
+                .collect(),
             ..Default::default()
         };
 
+        // Show cached data from the last run while the refresh below is in flight
+        let max_age = Duration::from_secs(config.refresh_interval_minutes * 60);
+        let cache_key = app.fetch_settings().cache_key();
+        if let Some(data) = cache::read_weather_cache(&cache_key, max_age) {
+            app.current_weathercode = data.current.weathercode;
+            app.display_label = config
+                .temperature_unit
+                .format_with_precision(data.current.temperature, config.temperature_decimal_places);
+            app.weather_data = Some(data);
+            app.update_relative_timestamp();
+        }
+        if let Some(data) = cache::read_air_quality_cache(&cache_key, max_age) {
+            app.current_aqi = Some((data.aqi, data.standard));
+            app.air_quality = Some(data);
+        }
+
         // Start with auto-location if enabled, otherwise fetch weather
         let task = if config.use_auto_location {
             Task::perform(
@@ -185,7 +480,7 @@ impl Application for Tempest {
         let interval_minutes = self.config.refresh_interval_minutes;
 
         // Use the interval value as part of the ID so subscription restarts when it changes
-        IcedSubscription::run_with_id(
+        let refresh = IcedSubscription::run_with_id(
             (std::any::TypeId::of::<Self>(), interval_minutes),
             async_stream::stream! {
                 let interval = Duration::from_secs(interval_minutes * 60);
@@ -194,7 +489,79 @@ impl Application for Tempest {
                     yield Message::Tick;
                 }
             },
-        )
+        );
+
+        // Keeps the "Updated: N min ago" label current
+        let timestamp = IcedSubscription::run_with_id(
+            (std::any::TypeId::of::<Self>(), "relative-timestamp"),
+            async_stream::stream! {
+                loop {
+                    tokio::time::sleep(Duration::from_secs(60)).await;
+                    yield Message::UpdateRelativeTimestamp;
+                }
+            },
+        );
+
+        let mut subscriptions = vec![refresh, timestamp];
+
+        // Re-render alert countdowns every minute while alerts are active, and keep
+        // ticking while the history has entries left to expire
+        if !self.alerts.is_empty() || !self.alert_history.is_empty() {
+            subscriptions.push(IcedSubscription::run_with_id(
+                (std::any::TypeId::of::<Self>(), "alert-timer"),
+                async_stream::stream! {
+                    loop {
+                        tokio::time::sleep(Duration::from_secs(60)).await;
+                        yield Message::AlertTimerTick;
+                    }
+                },
+            ));
+        }
+
+        // Periodically check whether auto-location should follow the user somewhere new
+        if self.config.use_auto_location {
+            subscriptions.push(IcedSubscription::run_with_id(
+                (std::any::TypeId::of::<Self>(), "location-redetect"),
+                async_stream::stream! {
+                    loop {
+                        tokio::time::sleep(LOCATION_REDETECT_INTERVAL).await;
+                        yield Message::RedetectLocation;
+                    }
+                },
+            ));
+        }
+
+        // Cycle panel metrics; keyed on the interval so changes take effect immediately
+        if self.config.panel_rotate {
+            let interval_secs = self.config.panel_rotate_interval_secs.max(1);
+            subscriptions.push(IcedSubscription::run_with_id(
+                (
+                    std::any::TypeId::of::<Self>(),
+                    "panel-rotate",
+                    interval_secs,
+                ),
+                async_stream::stream! {
+                    loop {
+                        tokio::time::sleep(Duration::from_secs(interval_secs)).await;
+                        yield Message::PanelRotateTick;
+                    }
+                },
+            ));
+        }
+
+        // Live search: keyed on the input so each keystroke restarts the delay
+        let query = self.city_input.trim().to_string();
+        if self.config.live_search && query.chars().count() >= 2 {
+            subscriptions.push(IcedSubscription::run_with_id(
+                (std::any::TypeId::of::<Self>(), "live-search", query),
+                async_stream::stream! {
+                    tokio::time::sleep(SEARCH_DEBOUNCE).await;
+                    yield Message::SearchCity;
+                },
+            ));
+        }
+
+        Subscription::batch(subscriptions)
     }
 
     fn on_close_requested(&self, id: Id) -> Option<Message> {
@@ -208,20 +575,13 @@ impl Application for Tempest {
     ///
     /// To get a better sense of which widgets are available, check out the `widget` module.
     fn view(&self) -> Element<'_, Self::Message> {
-        use chrono::{Local, Timelike};
         use cosmic::iced::Alignment;
 
-        // Determine if it's night time using actual sunrise/sunset data
-        let is_night = self
-            .weather_data
-            .as_ref()
-            .and_then(|w| w.forecast.first())
-            .map(|day| is_night_time(&day.sunrise, &day.sunset))
-            .unwrap_or_else(|| {
-                // Fallback to 6pm-6am if no weather data available
-                let hour = Local::now().hour();
-                !(6..18).contains(&hour)
-            });
+        if self.widget_mode {
+            return self.widget_view();
+        }
+
+        let is_night = self.is_night();
 
         // Use error icon if there's an error, otherwise use weather icon
         let icon_name = if self.error_message.is_some() {
@@ -232,7 +592,14 @@ impl Application for Tempest {
 
         let icon = widget::icon::from_name(icon_name).size(16).symbolic(true);
 
-        let temperature_text = text(&self.display_label);
+        let mut panel_items = self.panel_items();
+        let rotating = self.config.panel_rotate && panel_items.len() > 1;
+        if rotating {
+            let index = self.rotate_panel_index % panel_items.len();
+            panel_items = vec![panel_items.swap_remove(index)];
+        }
+        // Secondary metrics are drawn smaller when stacked under the temperature
+        let show_temperature = self.config.show_temperature_in_panel;
 
         let has_alerts = !self.alerts.is_empty();
         let alert_icon = widget::icon::from_name("dialog-warning-symbolic")
@@ -246,12 +613,12 @@ impl Application for Tempest {
             if has_alerts {
                 row = row.push(alert_icon);
             }
-            row = row.push(icon).push(temperature_text);
-            if self.config.show_aqi_in_panel {
-                if let Some((aqi, _)) = self.current_aqi {
+            row = row.push(icon);
+            for (i, item) in panel_items.into_iter().enumerate() {
+                if i > 0 {
                     row = row.push(text("|").size(12));
-                    row = row.push(text(crate::fl!("aqi-label", value = aqi)));
                 }
+                row = row.push(text(item));
             }
             Element::from(row)
         } else {
@@ -261,10 +628,12 @@ impl Application for Tempest {
             if has_alerts {
                 col = col.push(alert_icon);
             }
-            col = col.push(icon).push(temperature_text);
-            if self.config.show_aqi_in_panel {
-                if let Some((aqi, _)) = self.current_aqi {
-                    col = col.push(text(crate::fl!("aqi-label", value = aqi)).size(12));
+            col = col.push(icon);
+            for (i, item) in panel_items.into_iter().enumerate() {
+                if rotating || (i == 0 && show_temperature) {
+                    col = col.push(text(item));
+                } else {
+                    col = col.push(text(item).size(12));
                 }
             }
             Element::from(col)
@@ -274,7 +643,19 @@ impl Application for Tempest {
             .class(cosmic::theme::Button::AppletIcon)
             .on_press(Message::TogglePopup);
 
-        widget::autosize::autosize(button, widget::Id::unique()).into()
+        if !self.config.show_panel_tooltip {
+            return widget::autosize::autosize(button, widget::Id::unique()).into();
+        }
+
+        let summary = match self.weather_data {
+            Some(ref weather) => weather_summary(&weather.current, &self.config),
+            None if self.weather_loading => crate::fl!("loading-short"),
+            None => crate::fl!("weather-unavailable"),
+        };
+        let with_tooltip =
+            widget::tooltip(button, text(summary), widget::tooltip::Position::Bottom);
+
+        widget::autosize::autosize(with_tooltip, widget::Id::unique()).into()
     }
 
     fn view_window(&self, _id: Id) -> Element<'_, Self::Message> {
@@ -282,10 +663,18 @@ impl Application for Tempest {
         let l_loading = crate::fl!("loading");
         let l_failed_to_load = crate::fl!("failed-to-load");
         let l_retry = crate::fl!("retry");
+        let l_retry_aqi = crate::fl!("retry-aqi");
+        let l_aqi_failed = crate::fl!("aqi-failed");
+        let l_alerts_failed = crate::fl!("alerts-failed");
         let l_tab_current = crate::fl!("tab-current");
         let l_tab_hourly = crate::fl!("tab-hourly");
-        let l_tab_forecast = crate::fl!("tab-forecast");
+        let l_tab_forecast = crate::fl!("tab-forecast", days = self.config.forecast_length());
         let l_tab_air_quality = crate::fl!("tab-air-quality");
+        let l_tab_precipitation = crate::fl!("tab-precipitation");
+        let l_tab_astronomy = crate::fl!("tab-astronomy");
+        let l_tab_solar = crate::fl!("tab-solar");
+        let l_tab_marine = crate::fl!("tab-marine");
+        let l_tab_agricultural = crate::fl!("tab-agricultural");
         let l_air_quality_unavailable = crate::fl!("air-quality-unavailable");
         let l_alerts_disabled = crate::fl!("alerts-disabled");
         let l_alerts_enable_hint = crate::fl!("alerts-enable-hint");
@@ -294,12 +683,35 @@ impl Application for Tempest {
         let l_forecast_day = crate::fl!("forecast-day");
         let l_forecast_high = crate::fl!("forecast-high");
         let l_forecast_low = crate::fl!("forecast-low");
+        let l_forecast_rain_chance = crate::fl!("forecast-rain-chance");
+        let l_forecast_precip = crate::fl!("forecast-precip");
+        let l_forecast_uv = crate::fl!("forecast-uv");
+        let l_forecast_et0 = crate::fl!("forecast-et0");
         let l_forecast_conditions = crate::fl!("forecast-conditions");
 
+        // Content is inset from the popup edges by its padding
+        let content_width = self.config.popup_width as f32 - 20.0;
+
         let mut column = widget::column()
             .spacing(10)
             .padding(10)
-            .width(cosmic::iced::Length::Fixed(420.0));
+            .width(cosmic::iced::Length::Fixed(content_width));
+
+        // Weather photo banner, with a placeholder until a photo is available
+        if self.config.show_weather_photo {
+            column = column.push(match self.weather_photo {
+                Some(ref handle) => Element::from(
+                    widget::image(handle.clone())
+                        .width(cosmic::iced::Length::Fixed(content_width))
+                        .height(cosmic::iced::Length::Fixed(100.0))
+                        .content_fit(cosmic::iced::ContentFit::Cover),
+                ),
+                None => Element::from(
+                    widget::container(widget::Space::new(content_width, 100.0))
+                        .class(cosmic::theme::Container::Card),
+                ),
+            });
+        }
 
         // Header row with timestamp and action buttons
         let has_alerts = !self.alerts.is_empty();
@@ -313,11 +725,21 @@ impl Application for Tempest {
             .spacing(8)
             .align_y(cosmic::iced::Alignment::Center);
 
-        // Add timestamp if available
+        // Add timestamp if available, with a warning icon once the data is stale
         if let Some(ref formatted_time) = self.last_updated_display {
+            if self.is_data_stale() {
+                header = header.push(
+                    widget::icon::from_name("dialog-warning-symbolic")
+                        .size(14)
+                        .symbolic(true),
+                );
+            }
             let l_updated = crate::fl!("updated", time = formatted_time.as_str());
             header = header.push(text(l_updated).size(12));
         }
+        if self.fetch_in_flight {
+            header = header.push(text(crate::fl!("refreshing")).size(12));
+        }
 
         // Alert button - styled to stand out when alerts are active
         let alerts_btn = widget::button::icon(widget::icon::from_name(alerts_icon))
@@ -354,41 +776,104 @@ impl Application for Tempest {
 
         column = column.push(widget::divider::horizontal::default());
 
-        // Show error message if there is one
-        if let Some(ref error) = self.error_message {
+        // Stale, failed or offline banner above the data still on screen. A failed
+        // refresh only gets the full error card below when there's nothing to show.
+        let show_age =
+            self.weather_data.is_some() && (self.is_data_stale() || self.error_message.is_some());
+        if let Some(minutes) = self.data_age_minutes().filter(|_| show_age) {
+            let l_stale = if self.is_offline {
+                crate::fl!("data-stale-offline", minutes = minutes)
+            } else if self.error_message.is_some() {
+                crate::fl!("data-stale-error", minutes = minutes)
+            } else {
+                crate::fl!("data-stale", minutes = minutes)
+            };
             column = column.push(
                 widget::container(
-                    widget::column()
-                        .spacing(10)
-                        .push(widget::icon::from_name("dialog-error-symbolic").size(48))
-                        .push(text(l_failed_to_load).size(18))
-                        .push(text(error).size(14))
-                        .push(widget::button::standard(l_retry).on_press(Message::RefreshWeather)),
+                    widget::row()
+                        .spacing(8)
+                        .align_y(cosmic::iced::Alignment::Center)
+                        .push(
+                            widget::icon::from_name("dialog-warning-symbolic")
+                                .size(20)
+                                .symbolic(true),
+                        )
+                        .push(text(l_stale).size(14))
+                        .push(widget::horizontal_space())
+                        .push(
+                            widget::button::standard(l_retry.clone())
+                                .class(cosmic::theme::Button::Destructive)
+                                .on_press(Message::RefreshWeather),
+                        ),
                 )
-                .align_x(cosmic::iced::alignment::Horizontal::Center)
+                .padding(8)
+                .class(cosmic::theme::Container::Card)
+                .width(cosmic::iced::Length::Fill),
+            );
+        } else if self.is_offline {
+            column = column.push(
+                widget::container(
+                    widget::row()
+                        .spacing(8)
+                        .align_y(cosmic::iced::Alignment::Center)
+                        .push(
+                            widget::icon::from_name("network-offline-symbolic")
+                                .size(20)
+                                .symbolic(true),
+                        )
+                        .push(text(crate::fl!("offline")).size(14))
+                        .push(widget::horizontal_space())
+                        .push(
+                            widget::button::standard(l_retry.clone())
+                                .on_press(Message::RefreshWeather),
+                        ),
+                )
+                .padding(8)
+                .class(cosmic::theme::Container::Card)
                 .width(cosmic::iced::Length::Fill),
             );
-        } else if self.is_loading {
+        }
+
+        // First-run card replaces the tabs; the header still leads to Settings
+        if self.needs_onboarding() && self.active_tab != PopupTab::Settings {
+            column = column.push(self.onboarding_card());
+        } else if let Some(error) = self
+            .error_message
+            .as_ref()
+            .filter(|_| self.weather_data.is_none())
+        {
             column = column.push(
                 widget::container(
                     widget::column()
                         .spacing(10)
-                        .align_x(cosmic::iced::alignment::Horizontal::Center)
-                        .push(widget::icon::from_name("content-loading-symbolic").size(48))
-                        .push(text(l_loading).size(18)),
+                        .push(widget::icon::from_name("dialog-error-symbolic").size(48))
+                        .push(text(l_failed_to_load).size(18))
+                        .push(text(error).size(14))
+                        .push(widget::button::standard(l_retry).on_press(Message::RefreshWeather)),
                 )
                 .align_x(cosmic::iced::alignment::Horizontal::Center)
                 .width(cosmic::iced::Length::Fill),
             );
         } else if let Some(ref weather) = self.weather_data {
-            // Tab bar - 4 tabs only (Alerts/Settings accessible via header buttons)
-            let tab_bar = widget::row()
+            // Tab bar (Alerts/Settings accessible via header buttons)
+            let mut tab_bar = widget::row()
                 .spacing(8)
                 .align_y(cosmic::iced::Alignment::Center)
                 .push(self.tab_button(l_tab_current, PopupTab::Current))
                 .push(self.tab_button(l_tab_hourly, PopupTab::Hourly))
                 .push(self.tab_button(l_tab_forecast, PopupTab::Forecast))
-                .push(self.tab_button(l_tab_air_quality, PopupTab::AirQuality));
+                .push(self.tab_button(l_tab_air_quality, PopupTab::AirQuality))
+                .push(self.tab_button(l_tab_precipitation, PopupTab::Precipitation))
+                .push(self.tab_button(l_tab_astronomy, PopupTab::Astronomy));
+            if self.config.show_solar_tab {
+                tab_bar = tab_bar.push(self.tab_button(l_tab_solar, PopupTab::Solar));
+            }
+            if self.config.marine_enabled {
+                tab_bar = tab_bar.push(self.tab_button(l_tab_marine, PopupTab::Marine));
+            }
+            if self.config.show_agricultural {
+                tab_bar = tab_bar.push(self.tab_button(l_tab_agricultural, PopupTab::Agricultural));
+            }
 
             // Tab bar
             column = column.push(
@@ -398,129 +883,65 @@ impl Application for Tempest {
             );
             column = column.push(widget::divider::horizontal::default());
 
+            // Inline spinner while the active tab's data is being refreshed
+            let tab_loading = match self.active_tab {
+                PopupTab::AirQuality => self.aqi_loading,
+                PopupTab::Alerts => self.alerts_loading,
+                PopupTab::Settings => false,
+                _ => self.weather_loading,
+            };
+            if tab_loading {
+                column = column.push(loading_indicator(l_loading.clone()));
+            }
+
             // Tab content
             match self.active_tab {
                 PopupTab::Current => {
-                    // Temperature and condition
-                    column = column.push(
-                        widget::row()
-                            .spacing(10)
-                            .push(
-                                text(self.config.temperature_unit.format(weather.current.temperature))
-                                    .size(32),
-                            )
-                            .push(text(weathercode_to_description(
-                                weather.current.weathercode,
-                            ))),
-                    );
-
-                    // Feels like and humidity
-                    let feels_like_temp = format!("{:.0}{}", weather.current.feels_like, self.config.temperature_unit.symbol());
-                    let l_feels_like = crate::fl!("feels-like", temp = feels_like_temp.as_str());
-                    let l_humidity = crate::fl!("humidity", value = weather.current.humidity);
-                    column = column.push(
-                        widget::row()
-                            .spacing(20)
-                            .push(
-                                text(l_feels_like)
-                                .size(14),
-                            )
-                            .push(
-                                text(l_humidity).size(14),
-                            ),
-                    );
-
-                    // Wind information
-                    let wind_unit = self.config.measurement_system.wind_speed_unit();
-                    let wind_speed = format!("{:.1}", weather.current.windspeed);
-                    let wind_dir = wind_direction_to_compass(weather.current.wind_direction);
-                    let gust_speed = format!("{:.1}", weather.current.wind_gusts);
-                    let l_wind = crate::fl!("wind", speed = wind_speed.as_str(), unit = wind_unit, direction = wind_dir);
-                    let l_gusts = crate::fl!("gusts", speed = gust_speed.as_str(), unit = wind_unit);
-                    column = column.push(
-                        widget::row()
-                            .spacing(20)
-                            .push(
-                                text(l_wind)
-                                .size(14),
-                            )
-                            .push(
-                                text(l_gusts)
-                                .size(14),
-                            ),
-                    );
-
-                    // UV and cloud cover
-                    let uv_val = format!("{:.1}", weather.current.uv_index);
-                    let l_uv_index = crate::fl!("uv-index", value = uv_val.as_str());
-                    let l_cloud_cover = crate::fl!("cloud-cover", value = weather.current.cloud_cover);
-                    column = column.push(
-                        widget::row()
-                            .spacing(20)
-                            .push(
-                                text(l_uv_index).size(14),
-                            )
-                            .push(
-                                text(l_cloud_cover)
-                                    .size(14),
-                            ),
-                    );
-
-                    // Visibility and pressure
-                    let visibility = self
-                        .config
-                        .measurement_system
-                        .convert_visibility(weather.current.visibility);
-                    let visibility_unit = self.config.measurement_system.visibility_unit();
-                    let vis_val = format!("{:.1}", visibility);
-                    let pressure_val = format!("{:.0}", weather.current.pressure);
-                    let l_visibility = crate::fl!("visibility", value = vis_val.as_str(), unit = visibility_unit);
-                    let l_pressure = crate::fl!("pressure", value = pressure_val.as_str());
-                    column = column.push(
-                        widget::row()
-                            .spacing(20)
-                            .push(
-                                text(l_visibility)
-                                    .size(14),
-                            )
-                            .push(
-                                text(l_pressure)
-                                    .size(14),
-                            ),
-                    );
-
-                    // Sunrise/Sunset
-                    if let Some(first_day) = weather.forecast.first() {
-                        let sunrise_time = format_time(&first_day.sunrise);
-                        let sunset_time = format_time(&first_day.sunset);
-                        let l_sunrise = crate::fl!("sunrise", time = sunrise_time.as_str());
-                        let l_sunset = crate::fl!("sunset", time = sunset_time.as_str());
-                        column = column.push(
-                            widget::row()
-                                .spacing(20)
-                                .push(
-                                    text(l_sunrise)
-                                        .size(14),
-                                )
-                                .push(
-                                    text(l_sunset)
-                                        .size(14),
-                                ),
-                        );
-                    }
+                    column = column.push(self.current_conditions(weather));
                 }
                 PopupTab::AirQuality => {
                     if let Some(ref aq) = self.air_quality {
                         let label = aqi_standard_label(aq.standard);
                         let description = aqi_to_description(aq.aqi, aq.standard);
 
+                        let badge_color = aqi_color(aq.aqi, aq.standard);
+                        let badge = widget::container(
+                            text(aq.aqi.to_string()).size(16).font(cosmic::font::bold()),
+                        )
+                        .padding([2, 10])
+                        .class(cosmic::theme::Container::custom(move |_| {
+                            cosmic::iced::widget::container::Style {
+                                background: Some(cosmic::iced::Background::Color(badge_color)),
+                                text_color: Some(cosmic::iced::Color::BLACK),
+                                border: cosmic::iced::Border {
+                                    radius: 4.0.into(),
+                                    ..Default::default()
+                                },
+                                ..Default::default()
+                            }
+                        }));
+
                         column = column.push(
                             widget::row()
-                                .spacing(20)
-                                .push(text(format!("{}: {}", label, aq.aqi)).size(16))
+                                .spacing(10)
+                                .align_y(cosmic::iced::Alignment::Center)
+                                .push(text(format!("{}:", label)).size(16))
+                                .push(badge)
                                 .push(text(description).size(14)),
                         );
 
+                        if let Some((peak_time, peak_aqi)) = find_peak_aqi_hour(&aq.hourly_aqi) {
+                            let peak_hour = format_hour(&peak_time, self.config.time_format);
+                            column = column.push(
+                                text(crate::fl!(
+                                    "aqi-peak",
+                                    value = peak_aqi,
+                                    time = peak_hour.as_str()
+                                ))
+                                .size(13),
+                            );
+                        }
+
                         let pm25_val = format!("{:.1}", aq.pm2_5);
                         let pm10_val = format!("{:.1}", aq.pm10);
                         let l_pm25 = crate::fl!("pm25", value = pm25_val.as_str());
@@ -540,16 +961,54 @@ impl Application for Tempest {
                             widget::row()
                                 .spacing(20)
                                 .push(text(l_ozone).size(14))
-                                .push(
-                                    text(l_no2).size(14),
-                                ),
+                                .push(text(l_no2).size(14)),
                         );
 
                         let co_val = format!("{:.1}", aq.carbon_monoxide);
                         let l_co = crate::fl!("co", value = co_val.as_str());
-                        column =
-                            column.push(text(l_co).size(14));
-                    } else {
+                        column = column.push(text(l_co).size(14));
+
+                        if let Some(pollen) = self
+                            .pollen_data
+                            .as_ref()
+                            .filter(|_| self.config.pollen_enabled)
+                        {
+                            let rows: Vec<String> = [
+                                (crate::fl!("pollen-alder"), pollen.alder),
+                                (crate::fl!("pollen-birch"), pollen.birch),
+                                (crate::fl!("pollen-grass"), pollen.grass),
+                                (crate::fl!("pollen-mugwort"), pollen.mugwort),
+                                (crate::fl!("pollen-olive"), pollen.olive),
+                                (crate::fl!("pollen-ragweed"), pollen.ragweed),
+                            ]
+                            .into_iter()
+                            .filter_map(|(name, value)| {
+                                let value = format!("{:.0}", value?);
+                                Some(crate::fl!(
+                                    "pollen-value",
+                                    name = name.as_str(),
+                                    value = value.as_str()
+                                ))
+                            })
+                            .collect();
+                            // Species without a reading are hidden rather than shown as 0
+                            if !rows.is_empty() {
+                                column = column.push(
+                                    text(crate::fl!("pollen"))
+                                        .size(14)
+                                        .font(cosmic::font::bold()),
+                                );
+                                column = column.push(Self::metric_grid(rows));
+                            }
+                        }
+                    } else if let Some(ref error) = self.aqi_error {
+                        column = column.push(error_with_retry(
+                            l_aqi_failed,
+                            error,
+                            l_retry_aqi,
+                            Message::RetryAirQuality,
+                        ));
+                    } else if !self.aqi_loading {
                         column = column.push(text(l_air_quality_unavailable).size(14));
                     }
                 }
@@ -566,7 +1025,18 @@ impl Application for Tempest {
                             .align_x(cosmic::iced::alignment::Horizontal::Center)
                             .width(cosmic::iced::Length::Fill),
                         );
-                    } else if self.alerts.is_empty() {
+                    } else if let Some(error) = self
+                        .alerts_error
+                        .as_ref()
+                        .filter(|_| self.alerts.is_empty())
+                    {
+                        column = column.push(error_with_retry(
+                            l_alerts_failed,
+                            error,
+                            l_retry,
+                            Message::RetryAlerts,
+                        ));
+                    } else if self.alerts.is_empty() && !self.alerts_loading {
                         column = column.push(
                             widget::container(
                                 widget::column()
@@ -607,6 +1077,13 @@ impl Application for Tempest {
                                                 .push(text(&alert.event).size(14)),
                                         )
                                         .push(text(&alert.headline).size(12))
+                                        .push_maybe(alert.certainty.as_ref().map(|certainty| {
+                                            text(crate::fl!(
+                                                "alert-certainty",
+                                                value = certainty.as_str()
+                                            ))
+                                            .size(11)
+                                        }))
                                         .push_maybe(if alert.description.is_empty() {
                                             None
                                         } else {
@@ -620,10 +1097,50 @@ impl Application for Tempest {
                                                 .padding([4, 0, 4, 0]),
                                             )
                                         })
+                                        .push_maybe(alert.instruction.as_ref().map(|instruction| {
+                                            widget::column()
+                                                .spacing(2)
+                                                .push(
+                                                    text(crate::fl!("alert-action"))
+                                                        .size(11)
+                                                        .font(cosmic::font::bold()),
+                                                )
+                                                .push(
+                                                    widget::scrollable(text(instruction).size(11))
+                                                        .height(cosmic::iced::Length::Fixed(60.0)),
+                                                )
+                                        }))
                                         .push({
-                                            let expires_time = alert.expires.format("%b %d %I:%M %p").to_string();
-                                            text(crate::fl!("expires", time = expires_time.as_str()))
-                                            .size(10)
+                                            // Future alerts show when they begin instead of when they end
+                                            match alert.onset.filter(|_| alert.is_future()) {
+                                                Some(onset) => {
+                                                    let onset_time = format_date_time(
+                                                        &onset.with_timezone(&chrono::Local),
+                                                        self.config.time_format,
+                                                    );
+                                                    text(crate::fl!(
+                                                        "active-from",
+                                                        time = onset_time.as_str()
+                                                    ))
+                                                    .size(10)
+                                                }
+                                                None => {
+                                                    let expires_time = format_date_time(
+                                                        &alert
+                                                            .expires
+                                                            .with_timezone(&chrono::Local),
+                                                        self.config.time_format,
+                                                    );
+                                                    let remaining =
+                                                        format_duration_until(alert.expires);
+                                                    text(crate::fl!(
+                                                        "expires-countdown",
+                                                        time = expires_time.as_str(),
+                                                        remaining = remaining.as_str()
+                                                    ))
+                                                    .size(10)
+                                                }
+                                            }
                                         }),
                                 )
                                 .padding(8)
@@ -632,6 +1149,165 @@ impl Application for Tempest {
                             column = column.push(widget::divider::horizontal::default());
                         }
                     }
+
+                    // Alerts from the last 24 hours that are no longer active
+                    let past_alerts: Vec<&Alert> = self
+                        .alert_history
+                        .iter()
+                        .map(|(alert, _)| alert)
+                        .filter(|alert| !self.alerts.iter().any(|active| active.id == alert.id))
+                        .collect();
+                    if self.config.alerts_enabled && !past_alerts.is_empty() {
+                        let toggle_icon = if self.show_alert_history {
+                            "go-up-symbolic"
+                        } else {
+                            "go-down-symbolic"
+                        };
+                        column = column.push(
+                            widget::button::text(crate::fl!(
+                                "alert-history",
+                                count = past_alerts.len()
+                            ))
+                            .trailing_icon(widget::icon::from_name(toggle_icon))
+                            .on_press(Message::ToggleAlertHistory),
+                        );
+
+                        if self.show_alert_history {
+                            for alert in past_alerts {
+                                let ended = if alert.expires <= chrono::Utc::now() {
+                                    crate::fl!(
+                                        "alert-expired-ago",
+                                        time = format_duration_since(alert.expires)
+                                    )
+                                } else {
+                                    // Cancelled or replaced before its expiry
+                                    crate::fl!("alert-ended")
+                                };
+                                column = column.push(
+                                    widget::container(
+                                        widget::column()
+                                            .spacing(2)
+                                            .push(text(&alert.event).size(13))
+                                            .push(text(&alert.headline).size(11))
+                                            .push(text(ended).size(10)),
+                                    )
+                                    .padding(8)
+                                    .width(cosmic::iced::Length::Fill)
+                                    .class(
+                                        cosmic::theme::Container::custom(|theme| {
+                                            let mut muted: cosmic::iced::Color =
+                                                theme.cosmic().on_bg_color().into();
+                                            muted.a = 0.6;
+                                            cosmic::iced::widget::container::Style {
+                                                text_color: Some(muted),
+                                                ..Default::default()
+                                            }
+                                        }),
+                                    ),
+                                );
+                            }
+                        }
+                    }
+                }
+                PopupTab::Precipitation => {
+                    let system = self.config.measurement_system;
+                    let precip_unit = system.precipitation_unit();
+                    let format_precip = |mm: f32| match system {
+                        MeasurementSystem::Imperial => {
+                            format!("{:.2} {}", system.convert_precipitation(mm), precip_unit)
+                        }
+                        MeasurementSystem::Metric => {
+                            format!("{:.1} {}", system.convert_precipitation(mm), precip_unit)
+                        }
+                    };
+
+                    if let Some(today) = weather.forecast.first() {
+                        let total = format_precip(today.precipitation_sum);
+                        column = column.push(
+                            text(crate::fl!("precipitation-total", value = total.as_str()))
+                                .size(16),
+                        );
+                        column = column.push(widget::divider::horizontal::default());
+                    }
+
+                    // Hourly bars sized by chance of precipitation
+                    for hour in &weather.hourly {
+                        column = column.push(
+                            widget::row()
+                                .spacing(8)
+                                .align_y(cosmic::iced::Alignment::Center)
+                                .push(
+                                    text(format_hour(&hour.time, self.config.time_format))
+                                        .size(12)
+                                        .width(cosmic::iced::Length::Fixed(60.0)),
+                                )
+                                .push(Self::proportional_bar(
+                                    hour.precipitation_probability as f32 / 100.0,
+                                ))
+                                .push(
+                                    text(format!("{}%", hour.precipitation_probability))
+                                        .size(11)
+                                        .width(cosmic::iced::Length::Fixed(35.0)),
+                                )
+                                .push(
+                                    text(format_precip(hour.precipitation))
+                                        .size(11)
+                                        .width(cosmic::iced::Length::Fixed(60.0)),
+                                ),
+                        );
+                    }
+                }
+                PopupTab::Astronomy => {
+                    if let Some(today) = weather.forecast.first() {
+                        let time_format = self.config.time_format;
+                        let dawn = format_time(
+                            &shift_time(&today.sunrise, -CIVIL_TWILIGHT_MINUTES),
+                            time_format,
+                        );
+                        let dusk = format_time(
+                            &shift_time(&today.sunset, CIVIL_TWILIGHT_MINUTES),
+                            time_format,
+                        );
+                        let sunrise = format_time(&today.sunrise, time_format);
+                        let noon = format_time(&today.solar_noon, time_format);
+                        let sunset = format_time(&today.sunset, time_format);
+                        let entries = [
+                            (
+                                "weather-few-clouds-night-symbolic",
+                                crate::fl!("civil-dawn", time = dawn),
+                            ),
+                            (
+                                "daytime-sunrise-symbolic",
+                                crate::fl!("sunrise", time = sunrise),
+                            ),
+                            (
+                                "weather-clear-symbolic",
+                                crate::fl!("solar-noon", time = noon),
+                            ),
+                            (
+                                "daytime-sunset-symbolic",
+                                crate::fl!("sunset", time = sunset),
+                            ),
+                            (
+                                "weather-clear-night-symbolic",
+                                crate::fl!("civil-dusk", time = dusk),
+                            ),
+                        ];
+
+                        for (icon, label) in entries {
+                            column = column.push(
+                                widget::row()
+                                    .spacing(10)
+                                    .align_y(cosmic::iced::Alignment::Center)
+                                    .push(widget::icon::from_name(icon).size(20).symbolic(true))
+                                    .push(text(label).size(14)),
+                            );
+                        }
+                    }
+
+                    column = column.push(widget::divider::horizontal::default());
+
+                    column = column.push(Self::moon_phase_row());
                 }
                 PopupTab::Hourly => {
                     // 4-column grid layout for hourly forecast
@@ -640,10 +1316,12 @@ impl Application for Tempest {
                         let mut row = widget::row().spacing(8);
 
                         for hour in chunk {
-                            let cell = widget::column()
+                            let mut cell = widget::column()
                                 .spacing(4)
                                 .align_x(cosmic::iced::alignment::Horizontal::Center)
-                                .push(text(format_hour(&hour.time)).size(12))
+                                .push(
+                                    text(format_hour(&hour.time, self.config.time_format)).size(12),
+                                )
                                 .push(
                                     widget::icon::from_name(weathercode_to_icon_name(
                                         hour.weathercode,
@@ -653,12 +1331,27 @@ impl Application for Tempest {
                                     .symbolic(true),
                                 )
                                 .push(
-                                    text(self.config.temperature_unit.format(hour.temperature))
-                                        .size(14),
+                                    text(self.config.temperature_unit.format_with_precision(
+                                        hour.temperature,
+                                        self.config.temperature_decimal_places,
+                                    ))
+                                    .size(14),
                                 )
                                 .push(
                                     text(format!("{}%", hour.precipitation_probability)).size(11),
                                 );
+                            cell = cell.push(
+                                text(format!(
+                                    "{} {} {}",
+                                    wind_direction_to_arrow(hour.wind_direction),
+                                    format_wind_speed(hour.windspeed, 0, &self.config),
+                                    self.config.wind_speed_unit().label()
+                                ))
+                                .size(11),
+                            );
+                            if self.config.show_hourly_humidity {
+                                cell = cell.push(text(format!("💧 {}%", hour.humidity)).size(11));
+                            }
 
                             row = row.push(
                                 widget::container(cell)
@@ -679,6 +1372,24 @@ impl Application for Tempest {
                     }
                 }
                 PopupTab::Forecast => {
+                    // Temperature columns widen to fit feels-like values
+                    let show_feels_like = self.config.show_daily_feels_like;
+                    let temp_width = if show_feels_like { 100.0 } else { 45.0 };
+                    let format_temp = |actual: f32, feels: f32| {
+                        let unit = self.config.temperature_unit;
+                        let decimals = self.config.temperature_decimal_places;
+                        if show_feels_like {
+                            let feels_temp = unit.format_with_precision(feels, decimals);
+                            crate::fl!(
+                                "forecast-feels-like",
+                                temp = unit.format_with_precision(actual, decimals),
+                                feels = feels_temp.as_str()
+                            )
+                        } else {
+                            unit.format_with_precision(actual, decimals)
+                        }
+                    };
+
                     // Table header
                     column = column.push(
                         widget::row()
@@ -692,19 +1403,59 @@ impl Application for Tempest {
                             .push(
                                 text(l_forecast_high)
                                     .size(12)
-                                    .width(cosmic::iced::Length::Fixed(45.0)),
+                                    .width(cosmic::iced::Length::Fixed(temp_width)),
                             )
                             .push(
                                 text(l_forecast_low)
                                     .size(12)
-                                    .width(cosmic::iced::Length::Fixed(45.0)),
+                                    .width(cosmic::iced::Length::Fixed(temp_width)),
+                            )
+                            .push(
+                                text(l_forecast_rain_chance)
+                                    .size(12)
+                                    .width(cosmic::iced::Length::Fixed(40.0)),
+                            )
+                            .push(
+                                text(l_forecast_precip)
+                                    .size(12)
+                                    .width(cosmic::iced::Length::Fixed(55.0)),
                             )
+                            .push(
+                                text(l_forecast_uv)
+                                    .size(12)
+                                    .width(cosmic::iced::Length::Fixed(85.0)),
+                            )
+                            .push_maybe(self.config.show_agricultural.then(|| {
+                                text(l_forecast_et0)
+                                    .size(12)
+                                    .width(cosmic::iced::Length::Fixed(55.0))
+                            }))
                             .push(text(l_forecast_conditions).size(12)),
                     );
                     column = column.push(widget::divider::horizontal::default());
 
+                    if self.config.forecast_length() > 7 {
+                        column = column.push(text(crate::fl!("forecast-extended-note")).size(11));
+                    }
+                    let requested = self.config.forecast_length();
+                    if self.config.weather_provider == WeatherProvider::WeatherApiCom
+                        && weather.forecast.len() < usize::from(requested)
+                    {
+                        column = column.push(
+                            text(crate::fl!(
+                                "forecast-plan-limit",
+                                days = weather.forecast.len(),
+                                requested = requested
+                            ))
+                            .size(11),
+                        );
+                    }
+
                     // Data rows
                     for day in &weather.forecast {
+                        let precip_val = format_precipitation(day.precipitation_sum, &self.config);
+                        let et0_val =
+                            format_precipitation(day.et0_evapotranspiration, &self.config);
                         column = column.push(
                             widget::row()
                                 .spacing(8)
@@ -723,38 +1474,343 @@ impl Application for Tempest {
                                     .symbolic(true),
                                 )
                                 .push(
-                                    text(self.config.temperature_unit.format(day.temp_max))
+                                    text(format_temp(day.temp_max, day.apparent_temperature_max))
                                         .size(13)
-                                        .width(cosmic::iced::Length::Fixed(45.0)),
+                                        .width(cosmic::iced::Length::Fixed(temp_width)),
                                 )
                                 .push(
-                                    text(self.config.temperature_unit.format(day.temp_min))
+                                    text(format_temp(day.temp_min, day.apparent_temperature_min))
                                         .size(13)
-                                        .width(cosmic::iced::Length::Fixed(45.0)),
+                                        .width(cosmic::iced::Length::Fixed(temp_width)),
+                                )
+                                .push(
+                                    text(format!("{}%", day.precipitation_probability_max))
+                                        .size(12)
+                                        .width(cosmic::iced::Length::Fixed(40.0)),
+                                )
+                                .push(
+                                    text(precip_val)
+                                        .size(12)
+                                        .width(cosmic::iced::Length::Fixed(55.0)),
+                                )
+                                .push(
+                                    text(format!(
+                                        "{:.0} {}",
+                                        day.uv_index_max,
+                                        uv_index_to_category(day.uv_index_max)
+                                    ))
+                                    .size(12)
+                                    .width(cosmic::iced::Length::Fixed(85.0)),
                                 )
-                                .push(text(weathercode_to_description(day.weathercode)).size(12)),
+                                .push_maybe(self.config.show_agricultural.then(|| {
+                                    text(et0_val)
+                                        .size(12)
+                                        .width(cosmic::iced::Length::Fixed(55.0))
+                                }))
+                                .push(text(weathercode_to_description(day.weathercode)).size(12))
+                                .push_maybe(day.snow_depth.map(|depth| {
+                                    let system = self.config.measurement_system;
+                                    widget::row()
+                                        .spacing(2)
+                                        .align_y(cosmic::iced::Alignment::Center)
+                                        .push(
+                                            widget::icon::from_name("weather-snow-symbolic")
+                                                .size(14)
+                                                .symbolic(true),
+                                        )
+                                        .push(
+                                            text(format!(
+                                                "{:.0} {}",
+                                                system.convert_snow_depth(depth),
+                                                system.snow_depth_unit()
+                                            ))
+                                            .size(11),
+                                        )
+                                })),
                         );
                     }
                 }
-                PopupTab::Settings => {
-                    // Pre-bind all localized strings to extend their lifetime
-                    let l_temp_unit = crate::fl!("settings-temperature-unit");
-                    let l_auto_units = crate::fl!("settings-auto-units");
-                    let l_auto_units_hint = crate::fl!("settings-auto-units-hint");
-                    let l_auto_location = crate::fl!("settings-auto-location");
-                    let l_detect_now = crate::fl!("settings-detect-now");
-                    let l_current_location = crate::fl!("settings-current-location");
-                    let l_search_location = crate::fl!("settings-search-location");
-                    let l_search_placeholder = crate::fl!("settings-search-placeholder");
-                    let l_search = crate::fl!("settings-search");
+                PopupTab::Solar => {
+                    if let Some(ref solar) = self.solar_forecast {
+                        let generation = estimate_solar_generation(
+                            solar.total_kwh_today,
+                            self.config.solar_panel_kw,
+                        );
+                        let generation_val = format!("{:.1}", generation);
+                        let panel_val = format!("{:.1}", self.config.solar_panel_kw);
+                        let sun_hours_val = format!("{:.1}", solar.total_kwh_today);
+                        column = column.push(
+                            widget::column()
+                                .spacing(4)
+                                .push(
+                                    text(crate::fl!(
+                                        "solar-generation",
+                                        value = generation_val.as_str()
+                                    ))
+                                    .size(16),
+                                )
+                                .push(
+                                    text(crate::fl!(
+                                        "solar-details",
+                                        kw = panel_val.as_str(),
+                                        hours = sun_hours_val.as_str()
+                                    ))
+                                    .size(12),
+                                ),
+                        );
+                        column = column.push(widget::divider::horizontal::default());
+
+                        // Bar chart of hourly irradiance (daylight hours only)
+                        let peak = solar
+                            .hourly
+                            .iter()
+                            .map(|(_, w)| *w)
+                            .fold(0.0_f32, f32::max)
+                            .max(1.0);
+                        for (time, radiation) in solar.hourly.iter().filter(|(_, w)| *w > 0.0) {
+                            // Upcoming hours also have the direct/diffuse breakdown
+                            let split =
+                                weather
+                                    .hourly
+                                    .iter()
+                                    .find(|hour| &hour.time == time)
+                                    .map(|hour| {
+                                        let direct = format!("{:.0}", hour.direct_radiation);
+                                        let diffuse = format!("{:.0}", hour.diffuse_radiation);
+                                        text(crate::fl!(
+                                            "solar-split",
+                                            direct = direct.as_str(),
+                                            diffuse = diffuse.as_str()
+                                        ))
+                                        .size(10)
+                                    });
+                            column = column.push(
+                                widget::row()
+                                    .spacing(8)
+                                    .align_y(cosmic::iced::Alignment::Center)
+                                    .push(
+                                        text(format_hour(time, self.config.time_format))
+                                            .size(12)
+                                            .width(cosmic::iced::Length::Fixed(60.0)),
+                                    )
+                                    .push(cosmic::iced::widget::progress_bar(
+                                        0.0..=peak,
+                                        *radiation,
+                                    ))
+                                    .push(
+                                        text(format!("{:.0} W/m²", radiation))
+                                            .size(11)
+                                            .width(cosmic::iced::Length::Fixed(70.0)),
+                                    )
+                                    .push_maybe(split),
+                            );
+                        }
+                    } else {
+                        column = column.push(text(crate::fl!("solar-unavailable")).size(14));
+                    }
+                }
+                PopupTab::Marine => {
+                    if let Some(ref marine) = self.marine_data {
+                        let system = self.config.measurement_system;
+                        let unit = system.wave_height_unit();
+                        let height =
+                            |meters: f32| format!("{:.1}", system.convert_wave_height(meters));
+                        let wave_val = height(marine.wave_height);
+                        let period_val = format!("{:.0}", marine.wave_period);
+                        let swell_val = height(marine.swell_wave_height);
+                        let wind_wave_val = height(marine.wind_wave_height);
+                        column = column.push(Self::metric_grid(vec![
+                            crate::fl!(
+                                "marine-waves",
+                                height = wave_val.as_str(),
+                                unit = unit,
+                                direction = wind_direction_to_compass(marine.wave_direction)
+                            ),
+                            crate::fl!("marine-period", seconds = period_val.as_str()),
+                            crate::fl!(
+                                "marine-swell",
+                                height = swell_val.as_str(),
+                                unit = unit,
+                                direction = wind_direction_to_compass(marine.swell_wave_direction)
+                            ),
+                            crate::fl!(
+                                "marine-wind-waves",
+                                height = wind_wave_val.as_str(),
+                                unit = unit
+                            ),
+                        ]));
+                    } else {
+                        column = column.push(text(crate::fl!("marine-unavailable")).size(14));
+                    }
+                }
+                PopupTab::Agricultural => {
+                    let unit = self.config.temperature_unit;
+                    let decimals = self.config.temperature_decimal_places;
+                    if let Some(today) = weather.forecast.first() {
+                        let water =
+                            needs_watering(today.et0_evapotranspiration, today.precipitation_sum);
+                        column = column.push(
+                            text(if water {
+                                crate::fl!("agricultural-water-yes")
+                            } else {
+                                crate::fl!("agricultural-water-no")
+                            })
+                            .size(16),
+                        );
+                        column = column.push(widget::divider::horizontal::default());
+                    }
+
+                    column = column.push(
+                        widget::row()
+                            .spacing(8)
+                            .push(
+                                text(l_forecast_day)
+                                    .size(12)
+                                    .width(cosmic::iced::Length::Fixed(80.0)),
+                            )
+                            .push(
+                                text(l_forecast_et0)
+                                    .size(12)
+                                    .width(cosmic::iced::Length::Fixed(70.0)),
+                            )
+                            .push(
+                                text(crate::fl!("agricultural-soil"))
+                                    .size(12)
+                                    .width(cosmic::iced::Length::Fixed(70.0)),
+                            )
+                            .push(text(l_forecast_precip).size(12)),
+                    );
+                    column = column.push(widget::divider::horizontal::default());
+
+                    for day in &weather.forecast {
+                        let soil_val = day
+                            .soil_temperature
+                            .map(|temp| unit.format_with_precision(temp, decimals))
+                            .unwrap_or_else(|| "—".to_string());
+                        column = column.push(
+                            widget::row()
+                                .spacing(8)
+                                .push(
+                                    text(format_date(&day.date))
+                                        .size(13)
+                                        .width(cosmic::iced::Length::Fixed(80.0)),
+                                )
+                                .push(
+                                    text(format_precipitation(
+                                        day.et0_evapotranspiration,
+                                        &self.config,
+                                    ))
+                                    .size(12)
+                                    .width(cosmic::iced::Length::Fixed(70.0)),
+                                )
+                                .push(
+                                    text(soil_val)
+                                        .size(12)
+                                        .width(cosmic::iced::Length::Fixed(70.0)),
+                                )
+                                .push(
+                                    text(format_precipitation(day.precipitation_sum, &self.config))
+                                        .size(12),
+                                ),
+                        );
+                    }
+                }
+                PopupTab::Settings => {
+                    // Pre-bind all localized strings to extend their lifetime
+                    let l_temp_unit = crate::fl!("settings-temperature-unit");
+                    let l_temp_decimal = crate::fl!("settings-temperature-decimal");
+                    let l_time_format = crate::fl!("settings-time-format");
+                    let l_time_format_value = match self.config.time_format {
+                        TimeFormat::Auto => crate::fl!(
+                            "settings-time-format-auto",
+                            format = TimeFormat::Auto.resolve().label()
+                        ),
+                        explicit => explicit.label(),
+                    };
+                    let l_auto_units = crate::fl!("settings-auto-units");
+                    let l_auto_units_hint = crate::fl!("settings-auto-units-hint");
+                    let l_auto_location = crate::fl!("settings-auto-location");
+                    let l_detect_now = crate::fl!("settings-detect-now");
+                    let l_open_in_maps = crate::fl!("settings-open-in-maps");
+                    let l_current_location = crate::fl!("settings-current-location");
+                    let l_search_location = crate::fl!("settings-search-location");
+                    let l_live_search = crate::fl!("settings-live-search");
+                    let l_coordinates = crate::fl!("settings-coordinates");
+                    let l_set_coordinates = crate::fl!("settings-set-coordinates");
+                    let l_saved_locations = crate::fl!("settings-saved-locations");
+                    let l_save_location = crate::fl!("settings-save-location");
+                    let l_search_placeholder = crate::fl!("settings-search-placeholder");
+                    let l_search = crate::fl!("settings-search");
                     let l_refresh_interval = crate::fl!("settings-refresh-interval");
                     let l_minutes = crate::fl!("settings-minutes");
                     let l_weather_alerts = crate::fl!("settings-weather-alerts");
+                    let l_notify_severity = crate::fl!("settings-notify-severity");
+                    let l_notify_severity_value = match self.config.min_notification_severity {
+                        AlertSeverity::Moderate => crate::fl!("settings-notify-moderate"),
+                        AlertSeverity::Severe => crate::fl!("settings-notify-severe"),
+                        AlertSeverity::Extreme => crate::fl!("settings-notify-extreme"),
+                        _ => crate::fl!("settings-notify-all"),
+                    };
                     let l_alerts_hint = crate::fl!("settings-alerts-hint");
                     let l_show_aqi = crate::fl!("settings-show-aqi");
+                    let l_show_temperature = crate::fl!("settings-show-temperature");
+                    let l_show_wind = crate::fl!("settings-show-wind");
+                    let l_show_precip = crate::fl!("settings-show-precip");
+                    let l_panel_format = crate::fl!("settings-panel-format");
+                    let l_panel_format_hint = crate::fl!("settings-panel-format-hint");
+                    let l_panel_preview = crate::fl!("settings-panel-preview");
+                    let l_panel_tooltip = crate::fl!("settings-panel-tooltip");
+                    let l_panel_rotate = crate::fl!("settings-panel-rotate");
+                    let l_hourly_forecast = crate::fl!("settings-hourly-forecast");
+                    let l_hourly_hours =
+                        crate::fl!("settings-hours", count = self.config.hourly_hours());
+                    let l_daily_forecast = crate::fl!("settings-daily-forecast");
+                    let l_forecast_days =
+                        crate::fl!("tab-forecast", days = self.config.forecast_length());
+                    let l_wind_unit = crate::fl!("settings-wind-unit");
+                    let l_pressure_unit = crate::fl!("settings-pressure-unit");
+                    let l_wind_unit_value = match self.config.wind_unit {
+                        Some(unit) => unit.label().to_string(),
+                        None => crate::fl!(
+                            "settings-wind-unit-auto",
+                            unit = self.config.wind_speed_unit().label()
+                        ),
+                    };
+                    let l_show_hourly_humidity = crate::fl!("settings-show-hourly-humidity");
+                    let l_show_daily_feels_like = crate::fl!("settings-show-daily-feels-like");
+                    let l_show_moon_phase = crate::fl!("settings-show-moon-phase");
+                    let l_show_beaufort = crate::fl!("settings-show-beaufort");
+                    let l_show_fire_weather = crate::fl!("settings-show-fire-weather");
+                    let l_show_convective = crate::fl!("settings-show-convective");
+                    let l_show_solar = crate::fl!("settings-show-solar");
+                    let l_pollen_enabled = crate::fl!("settings-pollen");
+                    let l_marine_enabled = crate::fl!("settings-marine");
+                    let l_show_agricultural = crate::fl!("settings-show-agricultural");
+                    let l_show_weather_photo = crate::fl!("settings-show-weather-photo");
+                    let l_unsplash_key = crate::fl!("settings-unsplash-key");
+                    let l_api_key = crate::fl!("settings-api-key");
+                    let l_api_key_hint = crate::fl!("settings-api-key-hint");
+                    let l_advanced = crate::fl!("settings-advanced");
+                    let l_api_url = crate::fl!("settings-api-url");
+                    let l_provider = crate::fl!("settings-weather-provider");
+                    let l_weatherapi_key = crate::fl!("settings-weatherapi-key");
+                    let l_solar_panel_size = crate::fl!("settings-solar-panel-size");
+                    let l_startup_tab = crate::fl!("settings-startup-tab");
+                    let l_popup_width = crate::fl!("settings-popup-width");
+                    let l_popup_max_height = crate::fl!("settings-popup-max-height");
+                    let l_pixels = crate::fl!("settings-pixels");
                     let l_version = crate::fl!("settings-version");
                     let l_support = crate::fl!("settings-support");
                     let l_tip_kofi = crate::fl!("settings-tip-kofi");
+                    let l_whats_new = crate::fl!("settings-whats-new", version = VERSION);
+                    let l_export_settings = crate::fl!("settings-export");
+                    let l_import_settings = crate::fl!("settings-import");
+                    let l_export_include_keys = crate::fl!("settings-export-include-keys");
+                    let l_clear_cache = if self.clearing_cache {
+                        crate::fl!("settings-clearing-cache")
+                    } else {
+                        crate::fl!("settings-clear-cache")
+                    };
 
                     // Units section
                     column = column.push(settings::item(
@@ -763,6 +1819,24 @@ impl Application for Tempest {
                             .on_press(Message::ToggleTemperatureUnit),
                     ));
 
+                    column = column.push(settings::item(
+                        l_temp_decimal,
+                        widget::toggler(self.config.temperature_decimal_places > 0)
+                            .on_toggle(|_| Message::ToggleTemperatureDecimal),
+                    ));
+
+                    column = column.push(settings::item(
+                        l_time_format,
+                        widget::button::standard(l_time_format_value)
+                            .on_press(Message::CycleTimeFormat),
+                    ));
+
+                    column = column.push(settings::item(
+                        l_pressure_unit,
+                        widget::button::standard(self.config.pressure_unit.symbol())
+                            .on_press(Message::CyclePressureUnit),
+                    ));
+
                     column = column.push(settings::item(
                         l_auto_units,
                         widget::row()
@@ -794,9 +1868,56 @@ impl Application for Tempest {
 
                     column = column.push(settings::item(
                         l_current_location,
-                        text(&self.config.location_name).size(13),
+                        widget::row()
+                            .spacing(4)
+                            .align_y(cosmic::iced::Alignment::Center)
+                            .push(text(&self.config.location_name).size(13))
+                            .push(widget::tooltip(
+                                widget::button::icon(widget::icon::from_name(
+                                    "mark-location-symbolic",
+                                ))
+                                .on_press(Message::OpenInMaps)
+                                .padding(4),
+                                text(l_open_in_maps),
+                                widget::tooltip::Position::Bottom,
+                            )),
                     ));
 
+                    let is_saved = self.config.saved_locations.iter().any(|saved| {
+                        saved.latitude == self.config.latitude
+                            && saved.longitude == self.config.longitude
+                    });
+                    if !is_saved {
+                        column = column.push(settings::item(
+                            "",
+                            widget::button::standard(l_save_location)
+                                .on_press(Message::SaveCurrentLocation),
+                        ));
+                    }
+
+                    if !self.config.saved_locations.is_empty() {
+                        column = column.push(text(l_saved_locations).size(14));
+                        for (idx, saved) in self.config.saved_locations.iter().enumerate() {
+                            column = column.push(
+                                widget::row()
+                                    .spacing(8)
+                                    .align_y(cosmic::iced::Alignment::Center)
+                                    .push(
+                                        widget::button::text(&saved.name)
+                                            .on_press(Message::SwitchToSavedLocation(idx))
+                                            .padding(8)
+                                            .width(cosmic::iced::Length::Fill),
+                                    )
+                                    .push(
+                                        widget::button::icon(widget::icon::from_name(
+                                            "edit-delete-symbolic",
+                                        ))
+                                        .on_press(Message::RemoveSavedLocation(idx)),
+                                    ),
+                            );
+                        }
+                    }
+
                     if !self.config.use_auto_location {
                         column = column.push(settings::item(
                             l_search_location,
@@ -814,6 +1935,16 @@ impl Application for Tempest {
                                 ),
                         ));
 
+                        column = column.push(settings::item(
+                            l_live_search,
+                            widget::toggler(self.config.live_search)
+                                .on_toggle(|_| Message::ToggleLiveSearch),
+                        ));
+
+                        if let Some(ref error) = self.location_error {
+                            column = column.push(text(error).size(12));
+                        }
+
                         if !self.search_results.is_empty() {
                             for (idx, result) in self.search_results.iter().enumerate() {
                                 column = column.push(
@@ -824,6 +1955,40 @@ impl Application for Tempest {
                                 );
                             }
                         }
+
+                        // Button stays disabled until both fields parse as numbers
+                        let coordinates = self
+                            .latitude_input
+                            .trim()
+                            .parse::<f64>()
+                            .ok()
+                            .zip(self.longitude_input.trim().parse::<f64>().ok());
+                        column = column.push(settings::item(
+                            l_coordinates,
+                            widget::row()
+                                .spacing(8)
+                                .push(
+                                    widget::text_input("0.0000", &self.latitude_input)
+                                        .on_input(Message::UpdateLatitudeInput)
+                                        .width(cosmic::iced::Length::Fixed(90.0)),
+                                )
+                                .push(
+                                    widget::text_input("0.0000", &self.longitude_input)
+                                        .on_input(Message::UpdateLongitudeInput)
+                                        .width(cosmic::iced::Length::Fixed(90.0)),
+                                )
+                                .push(
+                                    widget::button::standard(l_set_coordinates).on_press_maybe(
+                                        coordinates.map(|(lat, lon)| {
+                                            Message::SetManualCoordinates(lat, lon)
+                                        }),
+                                    ),
+                                ),
+                        ));
+
+                        if let Some(ref error) = self.coordinates_error {
+                            column = column.push(text(error).size(12));
+                        }
                     }
 
                     column = column.push(widget::divider::horizontal::default());
@@ -854,175 +2019,696 @@ impl Application for Tempest {
                             .push(text(l_alerts_hint).size(11)),
                     ));
 
+                    if self.config.alerts_enabled {
+                        column = column.push(settings::item(
+                            l_notify_severity,
+                            widget::button::standard(l_notify_severity_value)
+                                .on_press(Message::CycleNotificationSeverity),
+                        ));
+                    }
+
                     column = column.push(settings::item(
                         l_show_aqi,
                         widget::toggler(self.config.show_aqi_in_panel)
                             .on_toggle(|_| Message::ToggleShowAqiInPanel),
                     ));
 
-                    column = column.push(widget::divider::horizontal::default());
+                    column = column.push(settings::item(
+                        l_show_temperature,
+                        widget::toggler(self.config.show_temperature_in_panel)
+                            .on_toggle(|_| Message::ToggleShowTemperatureInPanel),
+                    ));
 
-                    // About section
                     column = column.push(settings::item(
-                        l_version,
-                        text(VERSION).size(13),
+                        l_show_wind,
+                        widget::toggler(self.config.show_wind_in_panel)
+                            .on_toggle(|_| Message::ToggleShowWindInPanel),
                     ));
 
                     column = column.push(settings::item(
-                        l_support,
-                        widget::button::text(l_tip_kofi).on_press(Message::OpenUrl(
-                            "https://ko-fi.com/vintagetechie".to_string(),
-                        )),
+                        l_show_precip,
+                        widget::toggler(self.config.show_precip_in_panel)
+                            .on_toggle(|_| Message::ToggleShowPrecipInPanel),
                     ));
-                }
-            }
 
-        }
+                    column = column.push(settings::item(
+                        l_panel_format,
+                        widget::text_input("{temp}", &self.config.panel_format)
+                            .on_input(Message::UpdatePanelFormat)
+                            .width(cosmic::iced::Length::Fixed(180.0)),
+                    ));
+                    column = column.push(text(l_panel_format_hint).size(11));
+                    column = column.push(settings::item(
+                        l_panel_preview,
+                        text(self.panel_label()).size(13),
+                    ));
 
-        let scrollable = widget::scrollable(column).height(cosmic::iced::Length::Fill);
+                    column = column.push(settings::item(
+                        l_panel_tooltip,
+                        widget::toggler(self.config.show_panel_tooltip)
+                            .on_toggle(|_| Message::ToggleShowPanelTooltip),
+                    ));
 
-        self.core
-            .applet
-            .popup_container(scrollable)
-            .limits(Self::popup_limits())
-            .into()
-    }
+                    column = column.push(settings::item(
+                        l_panel_rotate,
+                        widget::toggler(self.config.panel_rotate)
+                            .on_toggle(|_| Message::TogglePanelRotate),
+                    ));
 
-    /// Application messages are handled here. The application state can be modified based on
-    /// what message was received. Tasks may be returned for asynchronous execution on a
-    /// background thread managed by the application's executor.
-    fn update(&mut self, message: Self::Message) -> Task<Self::Message> {
-        match message {
-            Message::TogglePopup => {
-                return if let Some(p) = self.popup.take() {
-                    destroy_popup(p)
-                } else {
-                    let new_id = Id::unique();
-                    self.popup.replace(new_id);
-                    let mut popup_settings = self.core.applet.get_popup_settings(
-                        self.core.main_window_id().unwrap(),
-                        new_id,
-                        None,
-                        None,
-                        None,
-                    );
-                    popup_settings.positioner.size_limits = Self::popup_limits();
-                    get_popup(popup_settings)
-                }
-            }
-            Message::PopupClosed(id) => {
-                if self.popup.as_ref() == Some(&id) {
-                    self.popup = None;
-                }
-            }
-            Message::RefreshWeather => {
-                self.is_loading = true;
-                self.error_message = None;
+                    column = column.push(settings::item(
+                        l_hourly_forecast,
+                        widget::button::standard(l_hourly_hours)
+                            .on_press(Message::CycleHourlyForecastHours),
+                    ));
 
-                let lat = self.config.latitude;
-                let lon = self.config.longitude;
-                let temp_unit = self.config.temperature_unit.api_param().to_string();
-                let wind_unit = self
-                    .config
-                    .measurement_system
-                    .wind_speed_api_param()
-                    .to_string();
-                let alerts_enabled = self.config.alerts_enabled;
+                    column = column.push(settings::item(
+                        l_daily_forecast,
+                        widget::button::standard(l_forecast_days)
+                            .on_press(Message::CycleForecastDays),
+                    ));
 
-                // Fetch weather and air quality in parallel
-                let weather_task = Task::perform(
-                    async move {
-                        fetch_weather(lat, lon, &temp_unit, &wind_unit)
-                            .await
-                            .map_err(|e| e.to_string())
-                    },
-                    |result| Action::App(Message::WeatherUpdated(result)),
-                );
+                    column = column.push(settings::item(
+                        l_wind_unit,
+                        widget::button::standard(l_wind_unit_value)
+                            .on_press(Message::CycleWindUnit),
+                    ));
 
-                let air_quality_task = Task::perform(
-                    async move { fetch_air_quality(lat, lon).await.map_err(|e| e.to_string()) },
-                    |result| Action::App(Message::AirQualityUpdated(result)),
-                );
+                    column = column.push(settings::item(
+                        l_show_hourly_humidity,
+                        widget::toggler(self.config.show_hourly_humidity)
+                            .on_toggle(|_| Message::ToggleShowHourlyHumidity),
+                    ));
 
-                // Fetch alerts if enabled
-                let alerts_task = if alerts_enabled {
-                    Task::perform(
-                        async move { fetch_alerts(lat, lon).await.map_err(|e| e.to_string()) },
-                        |result| Action::App(Message::AlertsUpdated(result)),
-                    )
-                } else {
-                    Task::none()
-                };
+                    column = column.push(settings::item(
+                        l_show_daily_feels_like,
+                        widget::toggler(self.config.show_daily_feels_like)
+                            .on_toggle(|_| Message::ToggleShowDailyFeelsLike),
+                    ));
 
-                return Task::batch([weather_task, air_quality_task, alerts_task]);
-            }
-            Message::WeatherUpdated(result) => {
-                self.is_loading = false;
+                    column = column.push(settings::item(
+                        l_show_moon_phase,
+                        widget::toggler(self.config.show_moon_phase)
+                            .on_toggle(|_| Message::ToggleShowMoonPhase),
+                    ));
 
-                match result {
-                    Ok(data) => {
-                        self.current_weathercode = data.current.weathercode;
-                        self.display_label =
-                            self.config.temperature_unit.format(data.current.temperature);
-                        self.weather_data = Some(data);
-                        self.error_message = None;
+                    column = column.push(settings::item(
+                        l_show_beaufort,
+                        widget::toggler(self.config.show_beaufort)
+                            .on_toggle(|_| Message::ToggleShowBeaufort),
+                    ));
 
-                        // Update last updated timestamp and cache formatted display
-                        let now = chrono::Local::now();
-                        self.config.last_updated = Some(now.timestamp());
-                        self.last_updated_display = Some(
-                            now.format("%I:%M %p")
-                                .to_string()
-                                .trim_start_matches('0')
-                                .to_string(),
-                        );
-                        self.save_config();
-                    }
-                    Err(e) => {
-                        tracing::error!("Failed to fetch weather: {}", e);
-                        self.display_label = "ERR".to_string();
-                        self.current_weathercode = 0;
-                        self.error_message = Some(e);
-                    }
-                }
-            }
-            Message::AirQualityUpdated(result) => match result {
-                Ok(data) => {
-                    self.current_aqi = Some((data.aqi, data.standard));
-                    self.air_quality = Some(data);
-                }
-                Err(e) => {
-                    tracing::warn!("Failed to fetch air quality: {}", e);
-                    self.current_aqi = None;
-                    self.air_quality = None;
-                }
-            },
-            Message::AlertsUpdated(result) => match result {
-                Ok(new_alerts) => {
-                    // Send notifications for new alerts
-                    for alert in &new_alerts {
-                        if !self.seen_alert_ids.contains(&alert.id) {
-                            self.send_alert_notification(alert);
-                            self.seen_alert_ids.insert(alert.id.clone());
-                        }
+                    column = column.push(settings::item(
+                        l_show_convective,
+                        widget::toggler(self.config.show_convective_data)
+                            .on_toggle(|_| Message::ToggleShowConvectiveData),
+                    ));
+
+                    column = column.push(settings::item(
+                        l_show_fire_weather,
+                        widget::toggler(self.config.show_fire_weather)
+                            .on_toggle(|_| Message::ToggleShowFireWeather),
+                    ));
+
+                    column = column.push(settings::item(
+                        l_marine_enabled,
+                        widget::toggler(self.config.marine_enabled)
+                            .on_toggle(|_| Message::ToggleMarineEnabled),
+                    ));
+
+                    column = column.push(settings::item(
+                        l_show_agricultural,
+                        widget::toggler(self.config.show_agricultural)
+                            .on_toggle(|_| Message::ToggleShowAgricultural),
+                    ));
+
+                    column = column.push(settings::item(
+                        l_pollen_enabled,
+                        widget::toggler(self.config.pollen_enabled)
+                            .on_toggle(|_| Message::TogglePollenEnabled),
+                    ));
+
+                    column = column.push(settings::item(
+                        l_show_solar,
+                        widget::toggler(self.config.show_solar_tab)
+                            .on_toggle(|_| Message::ToggleShowSolarTab),
+                    ));
+
+                    if self.config.show_solar_tab {
+                        column = column.push(settings::item(
+                            l_solar_panel_size,
+                            widget::row()
+                                .spacing(8)
+                                .align_y(cosmic::iced::Alignment::Center)
+                                .push(
+                                    widget::text_input("5.0", &self.solar_panel_input)
+                                        .on_input(Message::UpdateSolarPanelKw)
+                                        .width(cosmic::iced::Length::Fixed(60.0)),
+                                )
+                                .push(text("kW").size(13)),
+                        ));
                     }
-                    self.alerts = new_alerts;
+
+                    let startup_idx = STARTUP_TABS
+                        .iter()
+                        .position(|tab| *tab == self.config.startup_tab);
+                    column = column.push(settings::item(
+                        l_startup_tab,
+                        widget::dropdown(
+                            &self.startup_tab_labels,
+                            startup_idx,
+                            Message::SelectStartupTab,
+                        ),
+                    ));
+
+                    column = column.push(settings::item(
+                        l_popup_width,
+                        widget::row()
+                            .spacing(8)
+                            .align_y(cosmic::iced::Alignment::Center)
+                            .push(
+                                widget::text_input("440", &self.popup_width_input)
+                                    .on_input(Message::UpdatePopupWidth)
+                                    .width(cosmic::iced::Length::Fixed(60.0)),
+                            )
+                            .push(text(l_pixels.clone()).size(13)),
+                    ));
+
+                    column = column.push(settings::item(
+                        l_popup_max_height,
+                        widget::row()
+                            .spacing(8)
+                            .align_y(cosmic::iced::Alignment::Center)
+                            .push(
+                                widget::text_input("550", &self.popup_height_input)
+                                    .on_input(Message::UpdatePopupMaxHeight)
+                                    .width(cosmic::iced::Length::Fixed(60.0)),
+                            )
+                            .push(text(l_pixels).size(13)),
+                    ));
+
+                    column = column.push(settings::item(
+                        l_show_weather_photo,
+                        widget::toggler(self.config.show_weather_photo)
+                            .on_toggle(|_| Message::ToggleShowWeatherPhoto),
+                    ));
+
+                    if self.config.show_weather_photo {
+                        column = column.push(settings::item(
+                            l_unsplash_key,
+                            widget::secure_input("", &self.unsplash_key_input, None, true)
+                                .on_input(Message::UpdateUnsplashKey)
+                                .width(cosmic::iced::Length::Fixed(180.0)),
+                        ));
+                    }
+
+                    column = column.push(settings::item(
+                        l_provider,
+                        widget::button::standard(self.config.weather_provider.label())
+                            .on_press(Message::CycleWeatherProvider),
+                    ));
+
+                    if self.config.weather_provider == WeatherProvider::WeatherApiCom {
+                        column = column.push(settings::item(
+                            l_weatherapi_key,
+                            widget::secure_input("", &self.weatherapi_key_input, None, true)
+                                .on_input(Message::UpdateWeatherApiKey)
+                                .width(cosmic::iced::Length::Fixed(180.0)),
+                        ));
+                    }
+
+                    column = column.push(settings::item(
+                        l_api_key,
+                        widget::secure_input(
+                            "",
+                            &self.api_key_input,
+                            Some(Message::ToggleApiKeyVisibility),
+                            !self.show_api_key,
+                        )
+                        .on_input(Message::UpdateApiKey)
+                        .width(cosmic::iced::Length::Fixed(180.0)),
+                    ));
+                    column = column.push(text(l_api_key_hint).size(11));
+
+                    let advanced_icon = if self.show_advanced_settings {
+                        "go-up-symbolic"
+                    } else {
+                        "go-down-symbolic"
+                    };
+                    column = column.push(
+                        widget::button::text(l_advanced)
+                            .trailing_icon(widget::icon::from_name(advanced_icon))
+                            .on_press(Message::ToggleAdvancedSettings),
+                    );
+
+                    if self.show_advanced_settings {
+                        column = column.push(settings::item(
+                            l_api_url,
+                            widget::text_input("https://api.open-meteo.com", &self.api_url_input)
+                                .on_input(Message::UpdateApiUrl)
+                                .width(cosmic::iced::Length::Fixed(220.0)),
+                        ));
+                        if let Some(ref error) = self.api_url_error {
+                            column = column.push(text(error).size(12));
+                        }
+                    }
+
+                    column = column.push(widget::divider::horizontal::default());
+
+                    // About section
+                    column = column.push(settings::item(
+                        l_version,
+                        text(VERSION).size(13),
+                    ));
+
+                    column = column.push(settings::item(
+                        l_support,
+                        widget::button::text(l_tip_kofi).on_press(Message::OpenUrl(
+                            "https://ko-fi.com/vintagetechie".to_string(),
+                        )),
+                    ));
+
+                    let changelog_icon = if self.show_changelog {
+                        "go-up-symbolic"
+                    } else {
+                        "go-down-symbolic"
+                    };
+                    column = column.push(
+                        widget::button::text(l_whats_new)
+                            .trailing_icon(widget::icon::from_name(changelog_icon))
+                            .on_press(Message::ToggleChangelog),
+                    );
+                    if self.show_changelog {
+                        column = column.push(
+                            widget::container(
+                                widget::scrollable(
+                                    text(changelog_plain_text(CHANGELOG, VERSION)).size(12),
+                                )
+                                .height(cosmic::iced::Length::Fixed(200.0)),
+                            )
+                            .padding(8)
+                            .class(cosmic::theme::Container::Card),
+                        );
+                    }
+
+                    column = column.push(settings::item(
+                        l_export_include_keys,
+                        widget::toggler(self.export_include_keys)
+                            .on_toggle(|_| Message::ToggleExportIncludeKeys),
+                    ));
+                    column = column.push(
+                        widget::row()
+                            .spacing(8)
+                            .push(
+                                widget::button::standard(l_export_settings)
+                                    .on_press(Message::ExportConfig),
+                            )
+                            .push(
+                                widget::button::standard(l_import_settings)
+                                    .on_press(Message::ImportConfig),
+                            ),
+                    );
+                    if let Some(ref status) = self.config_transfer_status {
+                        column = column.push(text(status).size(12));
+                    }
+
+                    column = column.push(
+                        widget::button::standard(l_clear_cache)
+                            .on_press_maybe((!self.clearing_cache).then_some(Message::ClearCache)),
+                    );
+                }
+            }
+        } else if self.weather_loading {
+            column = column.push(loading_indicator(l_loading));
+        }
+
+        let scrollable = widget::scrollable(column).height(cosmic::iced::Length::Fill);
+
+        self.core
+            .applet
+            .popup_container(scrollable)
+            .limits(self.popup_limits())
+            .into()
+    }
+
+    /// Application messages are handled here. The application state can be modified based on
+    /// what message was received. Tasks may be returned for asynchronous execution on a
+    /// background thread managed by the application's executor.
+    fn update(&mut self, message: Self::Message) -> Task<Self::Message> {
+        match message {
+            Message::TogglePopup => {
+                return if let Some(p) = self.popup.take() {
+                    destroy_popup(p)
+                } else {
+                    let new_id = Id::unique();
+                    self.popup.replace(new_id);
+                    let photo_task = self.weather_photo_task();
+                    let mut popup_settings = self.core.applet.get_popup_settings(
+                        self.core.main_window_id().unwrap(),
+                        new_id,
+                        None,
+                        None,
+                        None,
+                    );
+                    popup_settings.positioner.size_limits = self.popup_limits();
+                    Task::batch([get_popup(popup_settings), photo_task])
+                }
+            }
+            Message::PopupClosed(id) => {
+                if self.popup.as_ref() == Some(&id) {
+                    self.popup = None;
+                }
+            }
+            Message::RefreshWeather => {
+                let lat = self.config.latitude;
+                let lon = self.config.longitude;
+
+                if let Err(e) = validate_coordinates(lat, lon) {
+                    self.weather_loading = false;
+                    self.clearing_cache = false;
+                    self.error_message = Some(e.to_string());
+                    return Task::none();
+                }
+
+                // Avoid parallel fetches answering out of order; the skipped refresh
+                // may carry new settings, so run it once the current one returns
+                if self.fetch_in_flight {
+                    self.refresh_queued = true;
+                    return Task::none();
+                }
+                self.fetch_in_flight = true;
+                self.in_flight_settings = Some(self.fetch_settings());
+
+                self.weather_loading = true;
+                self.aqi_loading = true;
+                self.alerts_loading = self.config.alerts_enabled;
+                self.error_message = None;
+
+                // Probe first so a dropped connection isn't reported as an API error
+                let provider = self.config.weather_provider;
+                let open_meteo = OpenMeteoApi::forecast(&self.config);
+                return Task::perform(
+                    async move { check_connectivity(provider, &open_meteo).await },
+                    |online| Action::App(Message::ConnectivityChecked(online)),
+                );
+            }
+            Message::ClearCache => {
+                cache::clear_cache();
+                self.weather_data = None;
+                self.air_quality = None;
+                self.current_aqi = None;
+                self.alerts.clear();
+                self.last_updated_display = None;
+                self.config.last_updated = None;
+                self.save_config();
+                // Without data an ETag would only earn a 304 with nothing to show
+                self.last_weather_etag = None;
+                self.last_aqi_etag = None;
+                self.clearing_cache = true;
+                self.weather_loading = true;
+                return Task::perform(async { Message::RefreshWeather }, Action::App);
+            }
+            Message::ConnectivityChecked(online) => {
+                if online {
+                    self.is_offline = false;
+                    return self.weather_fetch_task();
+                }
+                tracing::warn!("No internet connection; skipping weather refresh");
+                self.is_offline = true;
+                self.clearing_cache = false;
+                self.weather_loading = false;
+                self.aqi_loading = false;
+                self.alerts_loading = false;
+                self.fetch_in_flight = false;
+                self.refresh_queued = false;
+                self.in_flight_settings = None;
+            }
+            Message::WeatherUpdated(result) => {
+                self.weather_loading = false;
+                self.fetch_in_flight = false;
+                self.clearing_cache = false;
+
+                // Show the result either way, but a refresh skipped while it was in
+                // flight only needs to run if the settings changed since it started
+                let started_with = self.in_flight_settings.take();
+                let refetch = std::mem::take(&mut self.refresh_queued)
+                    && started_with.as_ref() != Some(&self.fetch_settings());
+                let refetch_task = if refetch {
+                    Task::perform(async { Message::RefreshWeather }, Action::App)
+                } else {
+                    Task::none()
+                };
+
+                match result {
+                    Ok(Conditional::NotModified) => {
+                        // Forecast unchanged since the last fetch; keep what's shown, but
+                        // it's confirmed current so it no longer counts as stale
+                        tracing::debug!("Weather not modified");
+                        self.error_message = None;
+                        self.config.last_updated = Some(chrono::Local::now().timestamp());
+                        self.update_relative_timestamp();
+                        self.save_config();
+                    }
+                    Ok(Conditional::Modified(data, etag)) => {
+                        self.last_weather_etag = etag;
+                        self.current_weathercode = data.current.weathercode;
+                        if let Some(elevation) = data.elevation {
+                            self.config.location_elevation_m = elevation;
+                        }
+                        self.display_label = self.config.temperature_unit.format_with_precision(
+                            data.current.temperature,
+                            self.config.temperature_decimal_places,
+                        );
+                        // Readings from somewhere else would show a bogus trend
+                        let coords = started_with
+                            .as_ref()
+                            .map_or((self.config.latitude, self.config.longitude), |settings| {
+                                (settings.latitude, settings.longitude)
+                            });
+                        if self.pressure_history_coords != Some(coords) {
+                            self.pressure_history.clear();
+                            self.pressure_history_coords = Some(coords);
+                        }
+                        if self.pressure_history.len() == PRESSURE_HISTORY_LEN {
+                            self.pressure_history.pop_front();
+                        }
+                        // Track sea-level pressure, the figure barometers and forecasts quote
+                        let temp_k = self
+                            .config
+                            .temperature_unit
+                            .to_celsius(data.current.temperature)
+                            + 273.15;
+                        // Providers without an elevation already report sea-level pressure
+                        let sea_level = to_sea_level_pressure(
+                            data.current.pressure,
+                            data.elevation.unwrap_or(0.0),
+                            temp_k,
+                        );
+                        self.pressure_history
+                            .push_back((chrono::Local::now(), sea_level));
+                        // Key by what the fetch used; the settings may have changed since
+                        let cache_key = started_with.as_ref().map_or_else(
+                            || self.fetch_settings().cache_key(),
+                            FetchSettings::cache_key,
+                        );
+                        cache::write_weather_cache(&cache_key, &data);
+                        self.weather_data = Some(data);
+                        self.error_message = None;
+
+                        // Update last updated timestamp and cache formatted display
+                        self.config.last_updated = Some(chrono::Local::now().timestamp());
+                        self.update_relative_timestamp();
+                        self.save_config();
+
+                        if self.popup.is_some() {
+                            return Task::batch([self.weather_photo_task(), refetch_task]);
+                        }
+                    }
+                    Err(e) => {
+                        tracing::error!("Failed to fetch weather: {}", e);
+                        self.display_label = "ERR".to_string();
+                        self.current_weathercode = 0;
+                        self.error_message = Some(weather_error_message(&e));
+                    }
+                }
+                return refetch_task;
+            }
+            Message::RetryAirQuality => {
+                self.aqi_error = None;
+                self.aqi_loading = true;
+                return self.air_quality_task();
+            }
+            Message::RetryAlerts => {
+                self.alerts_error = None;
+                self.alerts_loading = true;
+                return self.alerts_task();
+            }
+            Message::AirQualityUpdated(key, result) => {
+                // A result for a location we've since moved away from is of no use
+                if (key.latitude, key.longitude) != (self.config.latitude, self.config.longitude) {
+                    tracing::debug!("Dropping air quality for a previous location");
+                    return Task::none();
+                }
+                self.aqi_loading = false;
+                match result {
+                    Ok(Conditional::NotModified) => {
+                        self.aqi_error = None;
+                    }
+                    Ok(Conditional::Modified(data, etag)) => {
+                        self.last_aqi_etag = etag;
+                        self.aqi_error = None;
+                        cache::write_air_quality_cache(&key, &data);
+                        self.current_aqi = Some((data.aqi, data.standard));
+                        self.air_quality = Some(data);
+                    }
+                    Err(e) => {
+                        tracing::warn!("Failed to fetch air quality: {}", e);
+                        self.current_aqi = None;
+                        self.air_quality = None;
+                        self.aqi_error = Some(e);
+                    }
+                }
+            }
+            Message::AlertsUpdated(result) => {
+                self.alerts_loading = false;
+                match result {
+                    Ok(mut new_alerts) => {
+                        // Most severe first, then soonest-expiring
+                        new_alerts.sort_by(|a, b| {
+                            a.severity.cmp(&b.severity).then(a.expires.cmp(&b.expires))
+                        });
+
+                        // Send notifications for new alerts
+                        let mut seen_changed = false;
+                        for alert in &new_alerts {
+                            if !self.seen_alert_ids.contains(&alert.id) {
+                                self.send_alert_notification(alert);
+                                self.seen_alert_ids.insert(alert.id.clone());
+                                self.config.seen_alert_ids.push(SeenAlert {
+                                    id: alert.id.clone(),
+                                    expires: alert.expires.timestamp(),
+                                });
+                                seen_changed = true;
+                            }
+                        }
+
+                        // Forget expired alerts so the persisted list doesn't grow forever
+                        let now = chrono::Utc::now().timestamp();
+                        let before = self.config.seen_alert_ids.len();
+                        self.config.seen_alert_ids.retain(|seen| seen.expires > now);
+                        if self.config.seen_alert_ids.len() != before {
+                            let kept = &self.config.seen_alert_ids;
+                            self.seen_alert_ids
+                                .retain(|id| kept.iter().any(|seen| &seen.id == id));
+                            seen_changed = true;
+                        }
+                        if seen_changed {
+                            self.save_config();
+                        }
+
+                        // Record active alerts with the latest time they were reported
+                        let now = Utc::now();
+                        for alert in &new_alerts {
+                            match self
+                                .alert_history
+                                .iter_mut()
+                                .find(|(seen, _)| seen.id == alert.id)
+                            {
+                                Some(entry) => *entry = (alert.clone(), now),
+                                None => self.alert_history.push((alert.clone(), now)),
+                            }
+                        }
+
+                        self.alerts = new_alerts;
+                        self.prune_alert_history();
+                        self.alerts_error = None;
+                    }
+                    Err(e) => {
+                        tracing::warn!("Failed to fetch alerts: {}", e);
+                        self.alerts_error = Some(e);
+                    }
+                }
+            }
+            Message::SolarUpdated(result) => match result {
+                Ok(data) => {
+                    self.solar_forecast = Some(data);
                 }
                 Err(e) => {
-                    tracing::warn!("Failed to fetch alerts: {}", e);
+                    tracing::warn!("Failed to fetch solar forecast: {}", e);
+                    self.solar_forecast = None;
                 }
             },
+            Message::PollenUpdated(result) => match result {
+                Ok(data) => {
+                    self.pollen_data = Some(data);
+                }
+                Err(e) => {
+                    tracing::warn!("Failed to fetch pollen: {}", e);
+                    self.pollen_data = None;
+                }
+            },
+            Message::MarineUpdated(result) => match result {
+                Ok(data) => {
+                    self.marine_data = Some(data);
+                }
+                Err(e) => {
+                    tracing::warn!("Failed to fetch marine data: {}", e);
+                    self.marine_data = None;
+                }
+            },
+            Message::WeatherPhotoLoaded(result) => match result {
+                Ok(bytes) => {
+                    self.weather_photo = Some(widget::image::Handle::from_bytes(bytes));
+                }
+                Err(e) => {
+                    tracing::warn!("Failed to fetch weather photo: {}", e);
+                }
+            },
+            Message::ToggleAlertHistory => {
+                self.show_alert_history = !self.show_alert_history;
+            }
+            Message::AlertTimerTick => {
+                // Handling the message re-renders the countdowns
+                self.prune_alert_history();
+            }
+            Message::PanelRotateTick => {
+                let count = self.panel_items().len();
+                if count > 0 {
+                    self.rotate_panel_index = (self.rotate_panel_index + 1) % count;
+                }
+            }
+            Message::TogglePanelRotate => {
+                self.config.panel_rotate = !self.config.panel_rotate;
+                self.rotate_panel_index = 0;
+                self.save_config();
+            }
+            Message::ToggleShowWindInPanel => {
+                self.config.show_wind_in_panel = !self.config.show_wind_in_panel;
+                self.save_config();
+            }
+            Message::ToggleShowPrecipInPanel => {
+                self.config.show_precip_in_panel = !self.config.show_precip_in_panel;
+                self.save_config();
+            }
+            Message::UpdateRelativeTimestamp => {
+                self.update_relative_timestamp();
+            }
             Message::Tick => {
+                // Location changes are picked up separately by RedetectLocation, so a
+                // failing geolocation service never holds up the forecast
                 return Task::perform(async { Message::RefreshWeather }, Action::App);
             }
             Message::ToggleTemperatureUnit => {
-                // Toggle temperature unit and sync measurement system
+                // Cycle F -> C -> K and sync measurement system
                 match self.config.temperature_unit {
                     TemperatureUnit::Fahrenheit => {
                         self.config.temperature_unit = TemperatureUnit::Celsius;
                         self.config.measurement_system = MeasurementSystem::Metric;
                     }
                     TemperatureUnit::Celsius => {
+                        self.config.temperature_unit = TemperatureUnit::Kelvin;
+                        self.config.measurement_system = MeasurementSystem::Metric;
+                    }
+                    TemperatureUnit::Kelvin => {
                         self.config.temperature_unit = TemperatureUnit::Fahrenheit;
                         self.config.measurement_system = MeasurementSystem::Imperial;
                     }
@@ -1032,6 +2718,11 @@ impl Application for Tempest {
                 self.save_config();
                 return Task::perform(async { Message::RefreshWeather }, Action::App);
             }
+            Message::CycleNotificationSeverity => {
+                self.config.min_notification_severity =
+                    self.config.min_notification_severity.next_threshold();
+                self.save_config();
+            }
             Message::ToggleAlertsEnabled => {
                 self.config.alerts_enabled = !self.config.alerts_enabled;
                 if !self.config.alerts_enabled {
@@ -1044,6 +2735,212 @@ impl Application for Tempest {
                 self.config.show_aqi_in_panel = !self.config.show_aqi_in_panel;
                 self.save_config();
             }
+            Message::ToggleShowTemperatureInPanel => {
+                self.config.show_temperature_in_panel = !self.config.show_temperature_in_panel;
+                self.save_config();
+            }
+            Message::CycleHourlyForecastHours => {
+                self.config.cycle_hourly_hours();
+                self.save_config();
+                return Task::perform(async { Message::RefreshWeather }, Action::App);
+            }
+            Message::CycleWindUnit => {
+                self.config.cycle_wind_unit();
+                self.save_config();
+                return Task::perform(async { Message::RefreshWeather }, Action::App);
+            }
+            Message::ToggleExportIncludeKeys => {
+                self.export_include_keys = !self.export_include_keys;
+            }
+            Message::CopyWeatherSummary => {
+                let Some(ref weather) = self.weather_data else {
+                    return Task::none();
+                };
+                let updated = self
+                    .config
+                    .last_updated
+                    .and_then(|ts| DateTime::from_timestamp(ts, 0))
+                    .map(|time| {
+                        let local = time.with_timezone(&chrono::Local).to_rfc3339();
+                        format_time(&local, self.config.time_format)
+                    })
+                    .unwrap_or_default();
+                let summary = crate::fl!(
+                    "weather-summary-copy",
+                    location = self.config.location_name.as_str(),
+                    temp = temperature_text(weather.current.temperature, &self.config),
+                    condition = weathercode_to_description(weather.current.weathercode),
+                    humidity = weather.current.humidity,
+                    wind = panel_wind_text(&weather.current, &self.config),
+                    uv = format!("{:.0}", weather.current.uv_index),
+                    time = updated
+                );
+                self.copied_flash = true;
+                return Task::batch([
+                    cosmic::iced::clipboard::write(summary),
+                    Task::perform(tokio::time::sleep(Duration::from_secs(2)), |_| {
+                        Action::App(Message::ClearCopiedFlash)
+                    }),
+                ]);
+            }
+            Message::ClearCopiedFlash => {
+                self.copied_flash = false;
+            }
+            Message::ToggleChangelog => {
+                self.show_changelog = !self.show_changelog;
+            }
+            Message::ExportConfig => {
+                let export = ConfigExport::new(&self.config, self.export_include_keys);
+                let contents = match serde_json::to_string_pretty(&export) {
+                    Ok(contents) => contents,
+                    Err(e) => {
+                        self.config_transfer_status =
+                            Some(crate::fl!("settings-export-failed", error = e.to_string()));
+                        return Task::none();
+                    }
+                };
+                return Task::perform(
+                    async move {
+                        let Some(file) = rfd::AsyncFileDialog::new()
+                            .set_file_name("tempest-settings.json")
+                            .add_filter("JSON", &["json"])
+                            .save_file()
+                            .await
+                        else {
+                            return Ok(None);
+                        };
+                        file.write(contents.as_bytes())
+                            .await
+                            .map_err(|e| e.to_string())?;
+                        Ok(Some(file.path().display().to_string()))
+                    },
+                    |result| Action::App(Message::ConfigExported(result)),
+                );
+            }
+            Message::ConfigExported(result) => {
+                self.config_transfer_status = match result {
+                    Ok(Some(path)) => Some(crate::fl!("settings-exported", path = path)),
+                    Ok(None) => None,
+                    Err(e) => Some(crate::fl!("settings-export-failed", error = e)),
+                };
+            }
+            Message::ImportConfig => {
+                return Task::perform(
+                    async {
+                        let Some(file) = rfd::AsyncFileDialog::new()
+                            .add_filter("JSON", &["json"])
+                            .pick_file()
+                            .await
+                        else {
+                            return Ok(None);
+                        };
+                        ConfigExport::parse(&file.read().await).map(Some)
+                    },
+                    |result| Action::App(Message::ConfigImported(result)),
+                );
+            }
+            Message::ConfigImported(result) => match result {
+                Ok(Some(config)) => {
+                    self.apply_imported_config(config);
+                    self.config_transfer_status = Some(crate::fl!("settings-imported"));
+                    return Task::perform(async { Message::RefreshWeather }, Action::App);
+                }
+                Ok(None) => {}
+                Err(e) => {
+                    self.config_transfer_status =
+                        Some(crate::fl!("settings-import-failed", error = e));
+                }
+            },
+            Message::CycleTimeFormat => {
+                self.config.time_format = self.config.time_format.next();
+                self.save_config();
+            }
+            Message::CyclePressureUnit => {
+                self.config.pressure_unit = self.config.pressure_unit.next();
+                self.save_config();
+            }
+            Message::CycleForecastDays => {
+                self.config.cycle_forecast_days();
+                self.save_config();
+                return Task::perform(async { Message::RefreshWeather }, Action::App);
+            }
+            Message::ToggleShowHourlyHumidity => {
+                self.config.show_hourly_humidity = !self.config.show_hourly_humidity;
+                self.save_config();
+            }
+            Message::ToggleTemperatureDecimal => {
+                // Flips between 0 and 1 decimal places
+                self.config.temperature_decimal_places =
+                    u8::from(self.config.temperature_decimal_places == 0);
+                self.save_config();
+            }
+            Message::ToggleShowDailyFeelsLike => {
+                self.config.show_daily_feels_like = !self.config.show_daily_feels_like;
+                self.save_config();
+            }
+            Message::ToggleShowMoonPhase => {
+                self.config.show_moon_phase = !self.config.show_moon_phase;
+                self.save_config();
+            }
+            Message::ToggleShowBeaufort => {
+                self.config.show_beaufort = !self.config.show_beaufort;
+                self.save_config();
+            }
+            Message::ToggleShowConvectiveData => {
+                self.config.show_convective_data = !self.config.show_convective_data;
+                self.save_config();
+            }
+            Message::ToggleShowFireWeather => {
+                self.config.show_fire_weather = !self.config.show_fire_weather;
+                self.save_config();
+            }
+            Message::ToggleShowSolarTab => {
+                self.config.show_solar_tab = !self.config.show_solar_tab;
+                if !self.config.show_solar_tab {
+                    self.solar_forecast = None;
+                    if self.config.default_tab == PopupTab::Solar {
+                        self.config.default_tab = PopupTab::Current;
+                    }
+                }
+                self.save_config();
+                return Task::perform(async { Message::RefreshWeather }, Action::App);
+            }
+            Message::ToggleMarineEnabled => {
+                self.config.marine_enabled = !self.config.marine_enabled;
+                if !self.config.marine_enabled {
+                    self.marine_data = None;
+                    if self.config.default_tab == PopupTab::Marine {
+                        self.config.default_tab = PopupTab::Current;
+                    }
+                    if self.active_tab == PopupTab::Marine {
+                        self.active_tab = PopupTab::Settings;
+                    }
+                }
+                self.save_config();
+                if self.config.marine_enabled {
+                    return Task::perform(async { Message::RefreshWeather }, Action::App);
+                }
+            }
+            Message::ToggleShowAgricultural => {
+                self.config.show_agricultural = !self.config.show_agricultural;
+                if !self.config.show_agricultural {
+                    if self.config.default_tab == PopupTab::Agricultural {
+                        self.config.default_tab = PopupTab::Current;
+                    }
+                    if self.active_tab == PopupTab::Agricultural {
+                        self.active_tab = PopupTab::Settings;
+                    }
+                }
+                self.save_config();
+            }
+            Message::TogglePollenEnabled => {
+                self.config.pollen_enabled = !self.config.pollen_enabled;
+                self.save_config();
+                if self.config.pollen_enabled {
+                    return Task::perform(async { Message::RefreshWeather }, Action::App);
+                }
+                self.pollen_data = None;
+            }
             Message::ToggleAutoUnits => {
                 self.config.auto_units = !self.config.auto_units;
                 self.save_config();
@@ -1051,6 +2948,43 @@ impl Application for Tempest {
             Message::UpdateCityInput(value) => {
                 self.city_input = value;
             }
+            Message::SaveCurrentLocation => {
+                self.config.saved_locations.push(SavedLocation {
+                    name: self.config.location_name.clone(),
+                    latitude: self.config.latitude,
+                    longitude: self.config.longitude,
+                    country: self.location_country.clone().unwrap_or_default(),
+                });
+                self.save_config();
+            }
+            Message::SwitchToSavedLocation(idx) => {
+                if let Some(saved) = self.config.saved_locations.get(idx).cloned() {
+                    let units = self.units_for_country(&saved.country);
+                    let patch = ConfigPatch {
+                        latitude: Some(saved.latitude),
+                        longitude: Some(saved.longitude),
+                        location_name: Some(saved.name.clone()),
+                        use_auto_location: Some(false),
+                        manual_latitude: Some(saved.latitude),
+                        manual_longitude: Some(saved.longitude),
+                        manual_location_name: Some(saved.name),
+                        temperature_unit: units.map(|(temp, _)| temp),
+                        measurement_system: units.map(|(_, system)| system),
+                    };
+                    self.location_country = Some(saved.country);
+                    return self.update(Message::BulkUpdateConfig(patch));
+                }
+            }
+            Message::RemoveSavedLocation(idx) => {
+                if idx < self.config.saved_locations.len() {
+                    self.config.saved_locations.remove(idx);
+                    self.save_config();
+                }
+            }
+            Message::ToggleLiveSearch => {
+                self.config.live_search = !self.config.live_search;
+                self.save_config();
+            }
             Message::SearchCity => {
                 let city = self.city_input.clone();
                 if !city.is_empty() {
@@ -1062,30 +2996,106 @@ impl Application for Tempest {
             }
             Message::CitySearchResult(result) => match result {
                 Ok(results) => {
+                    self.location_error =
+                        results.is_empty().then(|| crate::fl!("search-no-results"));
                     self.search_results = results;
                 }
                 Err(e) => {
                     tracing::warn!("City search failed: {}", e);
+                    self.location_error = Some(crate::fl!("search-failed"));
                     self.search_results.clear();
                 }
             },
             Message::SelectLocation(idx) => {
                 if let Some(location) = self.search_results.get(idx) {
-                    let country = location.country.clone();
-                    self.config.latitude = location.latitude;
-                    self.config.longitude = location.longitude;
-                    self.config.location_name = location.display_name.clone();
-                    self.config.use_auto_location = false;
-                    // Update manual location storage
-                    self.config.manual_latitude = Some(location.latitude);
-                    self.config.manual_longitude = Some(location.longitude);
-                    self.config.manual_location_name = Some(location.display_name.clone());
-
-                    self.apply_units_for_country(&country);
+                    if let Err(e) = validate_coordinates(location.latitude, location.longitude) {
+                        tracing::warn!("Rejected search result {}: {}", location.display_name, e);
+                        self.error_message = Some(e.to_string());
+                        return Task::none();
+                    }
 
+                    let units = self.units_for_country(&location.country);
+                    let patch = ConfigPatch {
+                        latitude: Some(location.latitude),
+                        longitude: Some(location.longitude),
+                        location_name: Some(location.display_name.clone()),
+                        use_auto_location: Some(false),
+                        // Update manual location storage
+                        manual_latitude: Some(location.latitude),
+                        manual_longitude: Some(location.longitude),
+                        manual_location_name: Some(location.display_name.clone()),
+                        temperature_unit: units.map(|(temp, _)| temp),
+                        measurement_system: units.map(|(_, system)| system),
+                    };
+
+                    self.location_country = Some(location.country.clone());
                     self.city_input.clear();
                     self.search_results.clear();
-                    self.save_config();
+                    self.location_error = None;
+                    return self.update(Message::BulkUpdateConfig(patch));
+                }
+            }
+            Message::UpdateLatitudeInput(value) => {
+                self.latitude_input = value;
+                self.coordinates_error = None;
+            }
+            Message::UpdateLongitudeInput(value) => {
+                self.longitude_input = value;
+                self.coordinates_error = None;
+            }
+            Message::SetManualCoordinates(lat, lon) => {
+                if let Err(e) = validate_coordinates(lat, lon) {
+                    tracing::warn!("Rejected coordinates {}, {}: {}", lat, lon, e);
+                    self.coordinates_error = Some(e.to_string());
+                    return Task::none();
+                }
+                self.coordinates_error = None;
+
+                self.config.latitude = lat;
+                self.config.longitude = lon;
+                self.config.use_auto_location = false;
+                self.config.manual_latitude = Some(lat);
+                self.config.manual_longitude = Some(lon);
+                self.config.onboarding_done = true;
+                // Shown until reverse geocoding resolves a name
+                self.config.location_name = format!("{:.4}, {:.4}", lat, lon);
+
+                return Task::perform(
+                    async move {
+                        let name = reverse_geocode_city(lat, lon)
+                            .await
+                            .map_err(|e| e.to_string())?;
+                        let country = detect_country_from_coords(lat, lon)
+                            .await
+                            .unwrap_or_default();
+                        Ok((name, country))
+                    },
+                    |result| Action::App(Message::CoordinatesGeocoded(result)),
+                );
+            }
+            Message::CoordinatesGeocoded(result) => {
+                match result {
+                    Ok((name, country)) => {
+                        self.config.location_name = name;
+                        self.apply_units_for_country(&country);
+                        self.location_country = Some(country);
+                    }
+                    Err(e) => {
+                        tracing::warn!("Reverse geocoding failed: {}", e);
+                    }
+                }
+                self.config.manual_location_name = Some(self.config.location_name.clone());
+                self.save_config();
+                return Task::perform(async { Message::RefreshWeather }, Action::App);
+            }
+            Message::BulkUpdateConfig(patch) => {
+                let refresh = patch.affects_weather();
+                if patch.latitude.is_some() || patch.longitude.is_some() {
+                    self.config.onboarding_done = true;
+                }
+                self.config.apply(patch);
+                self.save_config();
+                if refresh {
                     return Task::perform(async { Message::RefreshWeather }, Action::App);
                 }
             }
@@ -1098,6 +3108,111 @@ impl Application for Tempest {
                     }
                 }
             }
+            Message::UpdatePopupWidth(value) => {
+                self.popup_width_input = value.clone();
+                if let Ok(width) = value.parse::<u32>() {
+                    if (380..=700).contains(&width) {
+                        self.config.popup_width = width;
+                        self.save_config();
+                    }
+                }
+            }
+            Message::UpdatePopupMaxHeight(value) => {
+                self.popup_height_input = value.clone();
+                if let Ok(height) = value.parse::<u32>() {
+                    if (400..=900).contains(&height) {
+                        self.config.popup_max_height = height;
+                        self.save_config();
+                    }
+                }
+            }
+            Message::ToggleShowWeatherPhoto => {
+                self.config.show_weather_photo = !self.config.show_weather_photo;
+                if !self.config.show_weather_photo {
+                    self.weather_photo = None;
+                    self.weather_photo_key = None;
+                }
+                self.save_config();
+                return self.weather_photo_task();
+            }
+            Message::UpdatePanelFormat(value) => {
+                self.config.panel_format = value;
+                self.save_config();
+            }
+            Message::ToggleShowPanelTooltip => {
+                self.config.show_panel_tooltip = !self.config.show_panel_tooltip;
+                self.save_config();
+            }
+            Message::UpdateUnsplashKey(value) => {
+                self.unsplash_key_input = value.clone();
+                let key = value.trim();
+                self.config.unsplash_access_key = if key.is_empty() {
+                    None
+                } else {
+                    Some(key.to_string())
+                };
+                self.save_config();
+            }
+            Message::UpdateApiKey(value) => {
+                self.api_key_input = value.clone();
+                let key = value.trim();
+                self.config.open_meteo_api_key = if key.is_empty() {
+                    None
+                } else {
+                    Some(key.to_string())
+                };
+                self.save_config();
+            }
+            Message::ToggleApiKeyVisibility => {
+                self.show_api_key = !self.show_api_key;
+            }
+            Message::ToggleAdvancedSettings => {
+                self.show_advanced_settings = !self.show_advanced_settings;
+            }
+            Message::UpdateApiUrl(value) => {
+                self.api_url_input = value.clone();
+                let url = value.trim();
+                if url.is_empty() {
+                    self.api_url_error = None;
+                    self.config.open_meteo_base_url = None;
+                } else {
+                    match reqwest::Url::parse(url) {
+                        Ok(parsed) if matches!(parsed.scheme(), "http" | "https") => {
+                            self.api_url_error = None;
+                            self.config.open_meteo_base_url = Some(url.to_string());
+                        }
+                        _ => {
+                            self.api_url_error = Some(crate::fl!("settings-api-url-invalid"));
+                            return Task::none();
+                        }
+                    }
+                }
+                self.save_config();
+            }
+            Message::CycleWeatherProvider => {
+                self.config.weather_provider = self.config.weather_provider.next();
+                self.save_config();
+                return Task::perform(async { Message::RefreshWeather }, Action::App);
+            }
+            Message::UpdateWeatherApiKey(value) => {
+                self.weatherapi_key_input = value.clone();
+                let key = value.trim();
+                self.config.weatherapi_key = if key.is_empty() {
+                    None
+                } else {
+                    Some(key.to_string())
+                };
+                self.save_config();
+            }
+            Message::UpdateSolarPanelKw(value) => {
+                self.solar_panel_input = value.clone();
+                if let Ok(kw) = value.parse::<f32>() {
+                    if kw > 0.0 && kw <= 1000.0 {
+                        self.config.solar_panel_kw = kw;
+                        self.save_config();
+                    }
+                }
+            }
             Message::ToggleAutoLocation => {
                 self.config.use_auto_location = !self.config.use_auto_location;
 
@@ -1128,6 +3243,10 @@ impl Application for Tempest {
                     return Task::perform(async { Message::RefreshWeather }, Action::App);
                 }
             }
+            Message::DismissOnboarding => {
+                self.config.onboarding_done = true;
+                self.save_config();
+            }
             Message::DetectLocation => {
                 return Task::perform(
                     async { detect_location().await.map_err(|e| e.to_string()) },
@@ -1136,17 +3255,35 @@ impl Application for Tempest {
             }
             Message::LocationDetected(result) => match result {
                 Ok((lat, lon, location_name, country)) => {
-                    self.config.latitude = lat;
-                    self.config.longitude = lon;
-                    self.config.location_name = location_name;
-
-                    self.apply_units_for_country(&country);
-
-                    self.save_config();
-                    return Task::perform(async { Message::RefreshWeather }, Action::App);
+                    return self.apply_detected_location(lat, lon, location_name, country);
                 }
                 Err(e) => {
                     tracing::error!("Failed to detect location: {}", e);
+                    self.location_error = Some(crate::fl!("location-detect-failed"));
+                    // Fall back to the saved location so the forecast still loads
+                    return Task::perform(async { Message::RefreshWeather }, Action::App);
+                }
+            },
+            Message::RedetectLocation => {
+                if self.config.use_auto_location {
+                    return Task::perform(
+                        async { detect_location().await.map_err(|e| e.to_string()) },
+                        |result| Action::App(Message::LocationRedetected(result)),
+                    );
+                }
+            }
+            Message::LocationRedetected(result) => match result {
+                Ok((lat, lon, location_name, country)) => {
+                    // Small shifts in the geolocation estimate shouldn't rename the location
+                    let distance =
+                        haversine_distance(self.config.latitude, self.config.longitude, lat, lon);
+                    if self.config.use_auto_location && distance > self.config.geofence_km {
+                        return self.apply_detected_location(lat, lon, location_name, country);
+                    }
+                }
+                Err(e) => {
+                    // The next refresh still runs for the saved location
+                    tracing::warn!("Failed to re-detect location: {}", e);
                 }
             },
             Message::SelectTab(tab) => {
@@ -1154,21 +3291,93 @@ impl Application for Tempest {
                 self.config.default_tab = tab;
                 self.save_config();
             }
+            Message::SelectStartupTab(idx) => {
+                if let Some(tab) = STARTUP_TABS.get(idx) {
+                    self.config.startup_tab = *tab;
+                    self.save_config();
+                }
+            }
             Message::OpenUrl(url) => {
                 if let Err(e) = open::that(&url) {
                     tracing::error!("Failed to open URL {}: {}", url, e);
                 }
             }
+            Message::OpenInMaps => {
+                let url = format!(
+                    "https://www.openstreetmap.org/?mlat={}&mlon={}",
+                    self.config.latitude, self.config.longitude
+                );
+                return self.update(Message::OpenUrl(url));
+            }
+        }
+        Task::none()
+    }
+
+    fn style(&self) -> Option<cosmic::iced_runtime::Appearance> {
+        Some(cosmic::applet::style())
+    }
+}
+
+impl Tempest {
+    /// True on a fresh install where auto-location is off and the placeholder
+    /// default location has never been replaced. Installs from before onboarding
+    /// existed have fetched weather already, so they skip it.
+    fn needs_onboarding(&self) -> bool {
+        !self.config.onboarding_done
+            && !self.config.use_auto_location
+            && self.config.last_updated.is_none()
+            && self.config.location_name == Config::default().location_name
+    }
+
+    /// First-run card asking for a location, shown instead of the tabs.
+    fn onboarding_card(&self) -> Element<'_, Message> {
+        let l_welcome = crate::fl!("onboarding-welcome");
+        let l_hint = crate::fl!("onboarding-hint");
+        let l_detect = crate::fl!("onboarding-detect");
+        let l_dismiss = crate::fl!("onboarding-dismiss");
+        let l_search_placeholder = crate::fl!("settings-search-placeholder");
+        let l_search = crate::fl!("settings-search");
+
+        let mut card = widget::column()
+            .spacing(12)
+            .align_x(cosmic::iced::Alignment::Center)
+            .push(widget::icon::from_name("find-location-symbolic").size(48))
+            .push(text(l_welcome).size(18))
+            .push(text(l_hint).size(13))
+            .push(widget::button::suggested(l_detect).on_press(Message::DetectLocation))
+            .push(
+                widget::row()
+                    .spacing(8)
+                    .push(
+                        widget::text_input(l_search_placeholder, &self.city_input)
+                            .on_input(Message::UpdateCityInput)
+                            .on_submit(|_| Message::SearchCity)
+                            .width(cosmic::iced::Length::Fill),
+                    )
+                    .push(widget::button::standard(l_search).on_press(Message::SearchCity)),
+            );
+
+        if let Some(ref error) = self.location_error {
+            card = card.push(text(error).size(12));
+        }
+
+        for (idx, result) in self.search_results.iter().enumerate() {
+            card = card.push(
+                widget::button::text(&result.display_name)
+                    .on_press(Message::SelectLocation(idx))
+                    .padding(8)
+                    .width(cosmic::iced::Length::Fill),
+            );
         }
-        Task::none()
-    }
 
-    fn style(&self) -> Option<cosmic::iced_runtime::Appearance> {
-        Some(cosmic::applet::style())
+        card = card.push(widget::button::text(l_dismiss).on_press(Message::DismissOnboarding));
+
+        widget::container(card.padding(16))
+            .class(cosmic::theme::Container::Card)
+            .width(cosmic::iced::Length::Fill)
+            .into()
     }
-}
 
-impl Tempest {
     fn save_config(&self) {
         if let Some(ref handler) = self.config_handler {
             if let Err(e) = self.config.write_entry(handler) {
@@ -1181,6 +3390,14 @@ impl Tempest {
     fn send_alert_notification(&self, alert: &Alert) {
         use notify_rust::{Notification, Urgency};
 
+        if !alert
+            .severity
+            .meets_threshold(self.config.min_notification_severity)
+        {
+            tracing::debug!("Skipping notification below threshold: {}", alert.event);
+            return;
+        }
+
         let urgency = match alert.severity {
             AlertSeverity::Extreme | AlertSeverity::Severe => Urgency::Critical,
             AlertSeverity::Moderate => Urgency::Normal,
@@ -1198,6 +3415,678 @@ impl Tempest {
         }
     }
 
+    /// Builds the current conditions view shared by the Current tab and widget mode.
+    fn current_conditions<'a>(&'a self, weather: &'a WeatherData) -> Element<'a, Message> {
+        let mut column = widget::column().spacing(10);
+
+        // Hero: large icon, temperature and condition, centered
+        let hero_icon = weathercode_to_icon_name(weather.current.weathercode, self.is_night());
+        column =
+            column.push(
+                widget::container(
+                    widget::column()
+                        .spacing(4)
+                        .align_x(cosmic::iced::alignment::Horizontal::Center)
+                        .push(widget::icon::from_name(hero_icon).size(64))
+                        .push(
+                            text(self.config.temperature_unit.format_with_precision(
+                                weather.current.temperature,
+                                self.config.temperature_decimal_places,
+                            ))
+                            .size(48)
+                            .class(cosmic::theme::Text::Color(temperature_color(
+                                self.config
+                                    .temperature_unit
+                                    .to_celsius(weather.current.temperature),
+                            ))),
+                        )
+                        .push(
+                            widget::row()
+                                .spacing(6)
+                                .align_y(cosmic::iced::Alignment::Center)
+                                .push(
+                                    text(weathercode_to_description(weather.current.weathercode))
+                                        .size(16),
+                                )
+                                .push(if self.copied_flash {
+                                    Element::from(text(crate::fl!("copied")).size(12))
+                                } else {
+                                    Element::from(
+                                        widget::button::icon(widget::icon::from_name(
+                                            "edit-copy-symbolic",
+                                        ))
+                                        .on_press(Message::CopyWeatherSummary)
+                                        .padding(4),
+                                    )
+                                }),
+                        ),
+                )
+                .align_x(cosmic::iced::alignment::Horizontal::Center)
+                .width(cosmic::iced::Length::Fill),
+            );
+
+        // Secondary metrics
+        let mut metrics: Vec<String> = Vec::new();
+
+        // Heat index or wind chill when conditions call for them, next to the API's apparent temp
+        let unit = self.config.temperature_unit;
+        let decimals = self.config.temperature_decimal_places;
+        let temp_f = unit.to_fahrenheit(weather.current.temperature);
+        let wind_mph = self
+            .config
+            .wind_speed_unit()
+            .to_kmh(weather.current.windspeed)
+            / 1.609_34;
+        let derived_feels_like = heat_index(temp_f, weather.current.humidity)
+            .map(|hi| {
+                let temp = unit.format_with_precision(unit.from_fahrenheit(hi), decimals);
+                crate::fl!("heat-index", temp = temp)
+            })
+            .or_else(|| {
+                wind_chill(temp_f, wind_mph).map(|wc| {
+                    let temp = unit.format_with_precision(unit.from_fahrenheit(wc), decimals);
+                    crate::fl!("wind-chill", temp = temp)
+                })
+            });
+
+        let feels_like_temp = unit.format_with_precision(weather.current.feels_like, decimals);
+        if let Some(derived) = derived_feels_like {
+            metrics.push(crate::fl!("apparent-temp", temp = feels_like_temp.as_str()));
+            metrics.push(derived);
+        } else {
+            metrics.push(crate::fl!("feels-like", temp = feels_like_temp.as_str()));
+        }
+        metrics.push(crate::fl!("humidity", value = weather.current.humidity));
+        let dewpoint_temp = unit.format_with_precision(weather.current.dewpoint, decimals);
+        metrics.push(crate::fl!("dew-point", temp = dewpoint_temp.as_str()));
+        let fog_likely = fog_risk(
+            unit.to_celsius(weather.current.temperature),
+            unit.to_celsius(weather.current.dewpoint),
+        );
+
+        let wind_unit = self.config.wind_speed_unit().label();
+        let wind_speed = format_wind_speed(weather.current.windspeed, 1, &self.config);
+        let wind_dir = wind_direction_to_compass(weather.current.wind_direction);
+        let gust_speed = format_wind_speed(weather.current.wind_gusts, 1, &self.config);
+        let wind = crate::fl!(
+            "wind",
+            speed = wind_speed.as_str(),
+            unit = wind_unit,
+            direction = wind_dir
+        );
+        if self.config.show_beaufort {
+            let wind_ms = self
+                .config
+                .wind_speed_unit()
+                .to_kmh(weather.current.windspeed)
+                / 3.6;
+            let force = ms_to_beaufort(wind_ms);
+            metrics.push(crate::fl!(
+                "wind-beaufort",
+                wind = wind,
+                force = force,
+                description = beaufort_to_description(force)
+            ));
+        } else {
+            metrics.push(wind);
+        }
+        metrics.push(crate::fl!(
+            "gusts",
+            speed = gust_speed.as_str(),
+            unit = wind_unit
+        ));
+
+        // Lightning risk when a thunderstorm is happening or forecast in the next few hours
+        let is_thunderstorm = |code: i32| matches!(code, 95 | 96 | 99);
+        let storm_nearby = is_thunderstorm(weather.current.weathercode)
+            || weather
+                .hourly
+                .iter()
+                .take(4)
+                .any(|hour| is_thunderstorm(hour.weathercode));
+        if self.config.show_convective_data && storm_nearby {
+            metrics.push(crate::fl!(
+                "lightning-risk",
+                level =
+                    lightning_risk_label(weather.current.lightning_potential, weather.current.cape),
+                cape = format!("{:.0}", weather.current.cape)
+            ));
+        }
+
+        // Fire danger only means something when it's hot and dry
+        let temp_c = unit.to_celsius(weather.current.temperature);
+        if self.config.show_fire_weather && temp_c > 25.0 && weather.current.humidity < 40 {
+            let wind_kmh = self
+                .config
+                .wind_speed_unit()
+                .to_kmh(weather.current.windspeed);
+            metrics.push(crate::fl!(
+                "fire-danger",
+                level = fire_weather_index(temp_c, weather.current.humidity, wind_kmh)
+            ));
+        }
+
+        let precipitation = self
+            .config
+            .measurement_system
+            .convert_precipitation(weather.current.precipitation);
+        let precipitation_unit = self.config.measurement_system.precipitation_unit();
+        // Inches need an extra decimal to show light rain
+        let precipitation_val = match self.config.measurement_system {
+            MeasurementSystem::Imperial => format!("{:.2}", precipitation),
+            MeasurementSystem::Metric => format!("{:.1}", precipitation),
+        };
+        metrics.push(crate::fl!(
+            "precipitation",
+            value = precipitation_val.as_str(),
+            unit = precipitation_unit
+        ));
+
+        let uv_val = format!("{:.1}", weather.current.uv_index);
+        metrics.push(crate::fl!(
+            "uv-index",
+            value = uv_val.as_str(),
+            category = uv_index_to_category(weather.current.uv_index)
+        ));
+        metrics.push(crate::fl!(
+            "cloud-cover",
+            value = weather.current.cloud_cover
+        ));
+
+        let visibility = self
+            .config
+            .measurement_system
+            .convert_visibility(weather.current.visibility);
+        let visibility_unit = self.config.measurement_system.visibility_unit();
+        let vis_val = format!("{:.1}", visibility);
+        let pressure_unit = self.config.pressure_unit;
+        let decimals = pressure_unit.decimals();
+        let pressure_val = format!(
+            "{:.*}",
+            decimals,
+            pressure_unit.convert(weather.current.pressure)
+        );
+        if fog_likely {
+            metrics.push(crate::fl!(
+                "visibility-fog",
+                value = vis_val.as_str(),
+                unit = visibility_unit
+            ));
+        } else {
+            metrics.push(crate::fl!(
+                "visibility",
+                value = vis_val.as_str(),
+                unit = visibility_unit
+            ));
+        }
+        // At altitude, station pressure reads well below sea level; show both.
+        // Providers without an elevation already report sea-level pressure.
+        let pressure = if weather.elevation.is_some() && self.config.location_elevation_m > 0.0 {
+            let temp_k = self
+                .config
+                .temperature_unit
+                .to_celsius(weather.current.temperature)
+                + 273.15;
+            let sea_level = to_sea_level_pressure(
+                weather.current.pressure,
+                self.config.location_elevation_m,
+                temp_k,
+            );
+            let sea_level_val = format!("{:.*}", decimals, pressure_unit.convert(sea_level));
+            crate::fl!(
+                "pressure-sea-level",
+                value = pressure_val.as_str(),
+                sea_level = sea_level_val.as_str(),
+                unit = pressure_unit.symbol()
+            )
+        } else {
+            crate::fl!(
+                "pressure",
+                value = pressure_val.as_str(),
+                unit = pressure_unit.symbol()
+            )
+        };
+        // A trend needs at least two readings
+        if self.pressure_history.len() >= 2 {
+            metrics.push(format!(
+                "{} {}",
+                pressure,
+                pressure_trend(&self.pressure_history)
+            ));
+        } else {
+            metrics.push(pressure);
+        }
+
+        if let Some(first_day) = weather.forecast.first() {
+            let sunrise_time = format_time(&first_day.sunrise, self.config.time_format);
+            let noon_time = format_time(&first_day.solar_noon, self.config.time_format);
+            let sunset_time = format_time(&first_day.sunset, self.config.time_format);
+            metrics.push(crate::fl!("sunrise", time = sunrise_time.as_str()));
+            metrics.push(crate::fl!("solar-noon", time = noon_time.as_str()));
+            metrics.push(crate::fl!("sunset", time = sunset_time.as_str()));
+        }
+
+        column = column.push(Self::metric_grid(metrics));
+
+        if self.config.show_moon_phase {
+            column = column.push(Self::moon_phase_row());
+        }
+
+        // Comfort index
+        let comfort = comfort_index(
+            self.config
+                .temperature_unit
+                .to_celsius(weather.current.temperature),
+            weather.current.humidity,
+            self.config
+                .wind_speed_unit()
+                .to_kmh(weather.current.windspeed),
+        );
+        let comfort_val = format!("{:.0}", comfort);
+        column = column.push(
+            text(crate::fl!(
+                "comfort",
+                emoji = comfort_emoji(comfort),
+                score = comfort_val.as_str(),
+                label = comfort_label(comfort)
+            ))
+            .size(14),
+        );
+
+        column.into()
+    }
+
+    /// Today's moon phase icon, name and illumination.
+    fn moon_phase_row<'a>() -> Element<'a, Message> {
+        let phase = moon_phase_fraction(chrono::Local::now().date_naive());
+        let illumination = format!("{:.0}", moon_illumination(phase));
+        widget::row()
+            .spacing(10)
+            .align_y(cosmic::iced::Alignment::Center)
+            .push(
+                // Icon themes have no per-phase moon icons; the phase is in the text
+                widget::icon::from_name("weather-clear-night-symbolic")
+                    .size(20)
+                    .symbolic(true),
+            )
+            .push(
+                text(crate::fl!(
+                    "moon-phase",
+                    name = moon_phase_name(phase),
+                    illumination = illumination
+                ))
+                .size(14),
+            )
+            .into()
+    }
+
+    /// Renders the current conditions as a standalone card for widget mode.
+    fn widget_view(&self) -> Element<'_, Message> {
+        let content: Element<'_, Message> = if let Some(ref error) = self.error_message {
+            widget::column()
+                .spacing(10)
+                .align_x(cosmic::iced::alignment::Horizontal::Center)
+                .push(widget::icon::from_name("dialog-error-symbolic").size(48))
+                .push(text(crate::fl!("failed-to-load")).size(18))
+                .push(text(error).size(14))
+                .into()
+        } else if let Some(ref weather) = self.weather_data {
+            widget::column()
+                .spacing(10)
+                .push(
+                    widget::container(text(&self.config.location_name).size(18))
+                        .align_x(cosmic::iced::alignment::Horizontal::Center)
+                        .width(cosmic::iced::Length::Fill),
+                )
+                .push(self.current_conditions(weather))
+                .into()
+        } else {
+            text(crate::fl!("loading")).size(18).into()
+        };
+
+        widget::container(content)
+            .class(cosmic::theme::Container::Card)
+            .padding(16)
+            .width(cosmic::iced::Length::Fill)
+            .into()
+    }
+
+    /// Loads today's weather photo if the banner is enabled and an Unsplash key is set.
+    fn weather_photo_task(&mut self) -> Task<Message> {
+        if !self.config.show_weather_photo {
+            return Task::none();
+        }
+        let (Some(key), Some(weather)) = (&self.config.unsplash_access_key, &self.weather_data)
+        else {
+            return Task::none();
+        };
+
+        let query = weathercode_to_description(weather.current.weathercode).to_string();
+        let photo_key = format!("{}-{}", chrono::Local::now().format("%Y-%m-%d"), query);
+        if self.weather_photo_key.as_ref() == Some(&photo_key) {
+            return Task::none();
+        }
+        let key = key.clone();
+        self.weather_photo_key = Some(photo_key);
+        Task::perform(
+            async move {
+                photo_cache::load_weather_photo(query, key)
+                    .await
+                    .map_err(|e| e.to_string())
+            },
+            |result| Action::App(Message::WeatherPhotoLoaded(result)),
+        )
+    }
+
+    /// Snapshot of the settings `weather_fetch_task` reads.
+    fn fetch_settings(&self) -> FetchSettings {
+        FetchSettings {
+            latitude: self.config.latitude,
+            longitude: self.config.longitude,
+            temperature_unit: self.config.temperature_unit,
+            wind_speed_unit: self.config.wind_speed_unit(),
+            hourly_hours: self.config.hourly_hours(),
+            forecast_days: self.config.forecast_length(),
+            provider: self.config.weather_provider,
+            weatherapi_key: self.config.weatherapi_key.clone(),
+            alerts_enabled: self.config.alerts_enabled,
+            show_solar_tab: self.config.show_solar_tab,
+            pollen_enabled: self.config.pollen_enabled,
+            marine_enabled: self.config.marine_enabled,
+            open_meteo: OpenMeteoApi::forecast(&self.config),
+        }
+    }
+
+    /// Fetches weather and every enabled extra (air quality, alerts, solar...) in parallel.
+    fn weather_fetch_task(&self) -> Task<Message> {
+        let lat = self.config.latitude;
+        let lon = self.config.longitude;
+        let temp_unit = self.config.temperature_unit.api_param().to_string();
+        let wind_unit = self.config.wind_speed_unit().api_param().to_string();
+        let hourly_hours = usize::from(self.config.hourly_hours());
+        let forecast_days = self.config.forecast_length();
+        let alerts_enabled = self.config.alerts_enabled;
+        let show_solar_tab = self.config.show_solar_tab;
+        let pollen_enabled = self.config.pollen_enabled;
+        let marine_enabled = self.config.marine_enabled;
+        let provider = self.config.weather_provider;
+        let weatherapi_key = self.config.weatherapi_key.clone();
+        let forecast_api = OpenMeteoApi::forecast(&self.config);
+        let solar_api = forecast_api.clone();
+        let air_quality_api = OpenMeteoApi::air_quality(&self.config);
+        let marine_api = OpenMeteoApi::marine(&self.config);
+        // Only worth asking for a 304 when there's data to keep
+        let etag = self
+            .weather_data
+            .as_ref()
+            .and(self.last_weather_etag.clone());
+
+        // Fetch weather and air quality in parallel
+        let weather_task = Task::perform(
+            async move {
+                match provider {
+                    WeatherProvider::OpenMeteo => {
+                        fetch_weather(
+                            &forecast_api,
+                            lat,
+                            lon,
+                            &temp_unit,
+                            &wind_unit,
+                            hourly_hours,
+                            forecast_days,
+                            etag.as_deref(),
+                        )
+                        .await
+                    }
+                    WeatherProvider::WeatherApiCom => match weatherapi_key {
+                        Some(key) => weatherapi::fetch_weather(
+                            lat,
+                            lon,
+                            &key,
+                            &temp_unit,
+                            &wind_unit,
+                            hourly_hours,
+                            forecast_days,
+                        )
+                        .await
+                        .map(|data| Conditional::Modified(data, None)),
+                        None => Err(WeatherError::Other(
+                            "WeatherAPI.com key is not set".to_string(),
+                        )),
+                    },
+                }
+                .map_err(Arc::new)
+            },
+            |result| Action::App(Message::WeatherUpdated(result)),
+        );
+
+        let air_quality_task = self.air_quality_task();
+
+        // Fetch alerts if enabled
+        let alerts_task = if alerts_enabled {
+            self.alerts_task()
+        } else {
+            Task::none()
+        };
+
+        // Fetch solar forecast if the tab is enabled
+        let solar_task = if show_solar_tab {
+            Task::perform(
+                async move {
+                    fetch_solar_forecast(&solar_api, lat, lon)
+                        .await
+                        .map_err(|e| e.to_string())
+                },
+                |result| Action::App(Message::SolarUpdated(result)),
+            )
+        } else {
+            Task::none()
+        };
+
+        let pollen_task = if pollen_enabled {
+            Task::perform(
+                async move {
+                    fetch_pollen(&air_quality_api, lat, lon)
+                        .await
+                        .map_err(|e| e.to_string())
+                },
+                |result| Action::App(Message::PollenUpdated(result)),
+            )
+        } else {
+            Task::none()
+        };
+
+        let marine_task = if marine_enabled {
+            Task::perform(
+                async move {
+                    fetch_marine(&marine_api, lat, lon)
+                        .await
+                        .map_err(|e| e.to_string())
+                },
+                |result| Action::App(Message::MarineUpdated(result)),
+            )
+        } else {
+            Task::none()
+        };
+
+        Task::batch([
+            weather_task,
+            air_quality_task,
+            alerts_task,
+            solar_task,
+            pollen_task,
+            marine_task,
+        ])
+    }
+
+    /// Fetches air quality for the current location.
+    fn air_quality_task(&self) -> Task<Message> {
+        // Keyed by the settings it starts with, so a late result can be recognized
+        let key = self.fetch_settings().cache_key();
+        let (lat, lon) = (key.latitude, key.longitude);
+        let api = OpenMeteoApi::air_quality(&self.config);
+        let etag = self.air_quality.as_ref().and(self.last_aqi_etag.clone());
+        Task::perform(
+            async move {
+                let result = fetch_air_quality(&api, lat, lon, etag.as_deref())
+                    .await
+                    .map_err(|e| e.to_string());
+                Message::AirQualityUpdated(key, result)
+            },
+            Action::App,
+        )
+    }
+
+    /// Fetches weather alerts for the current location.
+    fn alerts_task(&self) -> Task<Message> {
+        let lat = self.config.latitude;
+        let lon = self.config.longitude;
+        Task::perform(
+            async move { fetch_alerts(lat, lon).await.map_err(|e| e.to_string()) },
+            |result| Action::App(Message::AlertsUpdated(result)),
+        )
+    }
+
+    /// Panel text rendered from the configured format, or the loading/error label.
+    fn panel_label(&self) -> String {
+        match self.weather_data {
+            Some(ref weather) if self.error_message.is_none() => render_panel_label(
+                &self.config.panel_format,
+                weather,
+                self.current_aqi,
+                &self.config,
+            ),
+            _ => self.display_label.clone(),
+        }
+    }
+
+    /// Current wind for the panel, e.g. "↘ 12 mph NW" with the arrow showing the flow.
+    fn panel_wind_label(&self) -> Option<String> {
+        let current = &self.weather_data.as_ref()?.current;
+        Some(format!(
+            "{} {}",
+            wind_direction_to_arrow(current.wind_direction),
+            panel_wind_text(current, &self.config)
+        ))
+    }
+
+    /// Chance of precipitation in the next hour for the panel, e.g. "💧 45%".
+    fn panel_precip_label(&self) -> Option<String> {
+        let next_hour = next_hour_forecast(&self.weather_data.as_ref()?.hourly)?;
+        Some(format!("💧 {}%", next_hour.precipitation_probability))
+    }
+
+    /// Metrics shown in the panel, or cycled through when rotation is enabled.
+    /// Empty when every metric is hidden, leaving just the icon.
+    fn panel_items(&self) -> Vec<String> {
+        let mut items = Vec::new();
+        if self.config.show_temperature_in_panel {
+            items.push(self.panel_label());
+        }
+        if self.config.show_aqi_in_panel {
+            if let Some((aqi, _)) = self.current_aqi {
+                items.push(crate::fl!("aqi-label", value = aqi));
+            }
+        }
+        if self.config.show_wind_in_panel {
+            items.extend(self.panel_wind_label());
+        }
+        if self.config.show_precip_in_panel {
+            items.extend(self.panel_precip_label());
+        }
+        items
+    }
+
+    /// Minutes since the last successful weather update.
+    fn data_age_minutes(&self) -> Option<u64> {
+        let last = self.config.last_updated?;
+        Some((chrono::Local::now().timestamp() - last).max(0) as u64 / 60)
+    }
+
+    /// Refreshes the relative "Updated" label from the last update time.
+    fn update_relative_timestamp(&mut self) {
+        self.last_updated_display = self.data_age_minutes().map(|minutes| match minutes {
+            0 => crate::fl!("time-just-now"),
+            1..=59 => crate::fl!("time-minutes-ago", minutes = minutes),
+            _ => crate::fl!("time-hours-ago", hours = minutes / 60),
+        });
+    }
+
+    /// Returns true if the displayed weather is older than the stale threshold.
+    fn is_data_stale(&self) -> bool {
+        self.data_age_minutes()
+            .is_some_and(|minutes| minutes > self.config.stale_after_minutes())
+    }
+
+    /// Determines if it's night time using actual sunrise/sunset data.
+    fn is_night(&self) -> bool {
+        use chrono::{Local, Timelike};
+
+        self.weather_data
+            .as_ref()
+            .and_then(|w| w.forecast.first())
+            .map(|day| is_night_time(&day.sunrise, &day.sunset))
+            .unwrap_or_else(|| {
+                // Fallback to 6pm-6am if no weather data available
+                let hour = Local::now().hour();
+                !(6..18).contains(&hour)
+            })
+    }
+
+    /// Draws a horizontal accent-colored bar filling `fraction` (0.0-1.0) of the available width.
+    fn proportional_bar<'a>(fraction: f32) -> Element<'a, Message> {
+        let filled = (fraction.clamp(0.0, 1.0) * 100.0).round() as u16;
+        let mut bar = widget::row().width(cosmic::iced::Length::Fill);
+
+        if filled > 0 {
+            bar = bar.push(
+                widget::container(widget::Space::new(cosmic::iced::Length::Fill, 10))
+                    .width(cosmic::iced::Length::FillPortion(filled))
+                    .class(cosmic::theme::Container::custom(|theme| {
+                        cosmic::iced::widget::container::Style {
+                            background: Some(cosmic::iced::Background::Color(
+                                theme.cosmic().accent_color().into(),
+                            )),
+                            ..Default::default()
+                        }
+                    })),
+            );
+        }
+        if filled < 100 {
+            bar = bar.push(
+                widget::Space::new(cosmic::iced::Length::Fill, 10)
+                    .width(cosmic::iced::Length::FillPortion(100 - filled)),
+            );
+        }
+
+        bar.into()
+    }
+
+    /// Lays out metric labels in a 2-column grid.
+    fn metric_grid<'a>(metrics: Vec<String>) -> Element<'a, Message> {
+        let mut grid = widget::column().spacing(6);
+
+        for pair in metrics.chunks(2) {
+            let mut row = widget::row().spacing(20);
+            for metric in pair {
+                row = row.push(
+                    widget::container(text(metric.clone()).size(14))
+                        .width(cosmic::iced::Length::FillPortion(1)),
+                );
+            }
+            // Pad incomplete rows so the left column keeps its width
+            if pair.len() < 2 {
+                row = row.push(
+                    widget::container(widget::Space::new(0, 0))
+                        .width(cosmic::iced::Length::FillPortion(1)),
+                );
+            }
+            grid = grid.push(row);
+        }
+
+        grid.into()
+    }
+
     /// Creates a tab button, highlighted if it matches the active tab.
     fn tab_button(&self, label: String, tab: PopupTab) -> Element<'_, Message> {
         let btn = widget::button::text(label).on_press(Message::SelectTab(tab));
@@ -1209,24 +4098,330 @@ impl Tempest {
     }
 
     /// Returns the size limits for the popup window.
-    fn popup_limits() -> Limits {
+    fn popup_limits(&self) -> Limits {
+        let width = self.config.popup_width as f32;
         Limits::NONE
-            .min_width(440.0)
-            .max_width(440.0)
+            .min_width(width)
+            .max_width(width)
             .min_height(180.0)
-            .max_height(550.0)
+            .max_height(self.config.popup_max_height as f32)
+    }
+
+    /// Moves to a location from auto-detection and refreshes the forecast there.
+    fn apply_detected_location(
+        &mut self,
+        lat: f64,
+        lon: f64,
+        location_name: String,
+        country: String,
+    ) -> Task<Message> {
+        if let Err(e) = validate_coordinates(lat, lon) {
+            tracing::error!("Detected location is invalid: {}", e);
+            self.weather_loading = false;
+            self.error_message = Some(e.to_string());
+            return Task::none();
+        }
+
+        self.config.latitude = lat;
+        self.config.longitude = lon;
+        self.config.location_name = location_name;
+        self.config.onboarding_done = true;
+        self.location_error = None;
+
+        self.apply_units_for_country(&country);
+        self.location_country = Some(country);
+
+        self.save_config();
+        Task::perform(async { Message::RefreshWeather }, Action::App)
+    }
+
+    /// Returns the units to use for a country, or None if auto_units is disabled.
+    fn units_for_country(&self, country: &str) -> Option<(TemperatureUnit, MeasurementSystem)> {
+        if !self.config.auto_units {
+            return None;
+        }
+        if uses_imperial_units(country) {
+            Some((TemperatureUnit::Fahrenheit, MeasurementSystem::Imperial))
+        } else {
+            Some((TemperatureUnit::Celsius, MeasurementSystem::Metric))
+        }
     }
 
     /// Sets temperature and measurement units based on country if auto_units is enabled.
     fn apply_units_for_country(&mut self, country: &str) {
-        if self.config.auto_units {
-            if uses_imperial_units(country) {
-                self.config.temperature_unit = TemperatureUnit::Fahrenheit;
-                self.config.measurement_system = MeasurementSystem::Imperial;
-            } else {
-                self.config.temperature_unit = TemperatureUnit::Celsius;
-                self.config.measurement_system = MeasurementSystem::Metric;
-            }
+        if let Some((temperature_unit, measurement_system)) = self.units_for_country(country) {
+            self.config.temperature_unit = temperature_unit;
+            self.config.measurement_system = measurement_system;
         }
     }
+
+    /// Drops history entries more than 24 hours past their end, which is their expiry
+    /// or the last time the feed reported them, whichever came first.
+    fn prune_alert_history(&mut self) {
+        let cutoff = Utc::now() - chrono::Duration::hours(24);
+        let active = &self.alerts;
+        self.alert_history.retain(|(alert, last_seen)| {
+            active.iter().any(|a| a.id == alert.id) || alert.expires.min(*last_seen) > cutoff
+        });
+    }
+
+    /// Replaces the config with an imported one, keeping this machine's alert and
+    /// update history, and refreshes every settings field and runtime setting from it.
+    fn apply_imported_config(&mut self, mut config: Config) {
+        config.seen_alert_ids = std::mem::take(&mut self.config.seen_alert_ids);
+        config.last_updated = self.config.last_updated;
+        // Exports leave keys out by default; keep the ones already set
+        config.open_meteo_api_key = config
+            .open_meteo_api_key
+            .or(self.config.open_meteo_api_key.take());
+        config.weatherapi_key = config.weatherapi_key.or(self.config.weatherapi_key.take());
+        config.unsplash_access_key = config
+            .unsplash_access_key
+            .or(self.config.unsplash_access_key.take());
+        self.config = config;
+
+        self.refresh_input = self.config.refresh_interval_minutes.to_string();
+        self.popup_width_input = self.config.popup_width.to_string();
+        self.popup_height_input = self.config.popup_max_height.to_string();
+        self.solar_panel_input = self.config.solar_panel_kw.to_string();
+        self.unsplash_key_input = self.config.unsplash_access_key.clone().unwrap_or_default();
+        self.api_key_input = self.config.open_meteo_api_key.clone().unwrap_or_default();
+        self.api_url_input = self.config.open_meteo_base_url.clone().unwrap_or_default();
+        self.api_url_error = None;
+        self.weatherapi_key_input = self.config.weatherapi_key.clone().unwrap_or_default();
+
+        set_max_retry_attempts(self.config.max_retry_attempts);
+        self.save_config();
+    }
+}
+
+/// Fills in a panel format string such as "{temp} | AQI {aqi}" from current conditions.
+/// {precip} is the chance of precipitation in the next hour, like the panel's precipitation
+/// item; {precip_amount} is the amount falling now. Placeholders without data (e.g. {aqi}
+/// before air quality loads) become empty.
+fn render_panel_label(
+    format: &str,
+    weather: &WeatherData,
+    aqi: Option<(i32, AqiStandard)>,
+    config: &Config,
+) -> String {
+    let current = &weather.current;
+    let precip_chance = next_hour_forecast(&weather.hourly)
+        .map(|hour| format!("{}%", hour.precipitation_probability))
+        .unwrap_or_default();
+
+    format
+        .replace("{temp}", &temperature_text(current.temperature, config))
+        .replace(
+            "{aqi}",
+            &aqi.map(|(value, _)| value.to_string()).unwrap_or_default(),
+        )
+        .replace("{wind}", &panel_wind_text(current, config))
+        .replace(
+            "{precip_amount}",
+            &format_precipitation(current.precipitation, config),
+        )
+        .replace("{precip}", &precip_chance)
+        .replace("{humidity}", &format!("{}%", current.humidity))
+        .replace(
+            "{condition}",
+            weathercode_to_description(current.weathercode),
+        )
+}
+
+/// The hourly entry for the coming hour. The forecast can be several refreshes old,
+/// so this looks for the first hour still ahead rather than trusting its position.
+fn next_hour_forecast(hourly: &[HourlyForecast]) -> Option<&HourlyForecast> {
+    let now = chrono::Local::now().naive_local();
+    hourly
+        .iter()
+        .find(|hour| parse_local_timestamp(&hour.time).is_some_and(|time| time > now))
+}
+
+/// Formats a fetched wind speed in the configured unit; Beaufort is shown as a whole force.
+fn format_wind_speed(speed: f32, decimals: usize, config: &Config) -> String {
+    match config.wind_speed_unit() {
+        WindSpeedUnit::Beaufort => to_beaufort(speed).to_string(),
+        _ => format!("{:.*}", decimals, speed),
+    }
+}
+
+/// Formats a millimetre amount with the unit; inches need an extra decimal.
+fn format_precipitation(mm: f32, config: &Config) -> String {
+    let system = config.measurement_system;
+    let value = system.convert_precipitation(mm);
+    match system {
+        MeasurementSystem::Imperial => format!("{:.2} {}", value, system.precipitation_unit()),
+        MeasurementSystem::Metric => format!("{:.1} {}", value, system.precipitation_unit()),
+    }
+}
+
+/// Formats a temperature with the configured unit and decimal places.
+fn temperature_text(temp: f32, config: &Config) -> String {
+    config
+        .temperature_unit
+        .format_with_precision(temp, config.temperature_decimal_places)
+}
+
+/// One-line summary for the panel tooltip, e.g. "Partly cloudy, 72°F. Humidity 65%. Wind 12 mph SW."
+fn weather_summary(weather: &CurrentWeather, config: &Config) -> String {
+    let temp = temperature_text(weather.temperature, config);
+    let wind = panel_wind_text(weather, config);
+    crate::fl!(
+        "panel-tooltip",
+        condition = weathercode_to_description(weather.weathercode),
+        temp = temp.as_str(),
+        humidity = weather.humidity,
+        wind = wind.as_str()
+    )
+}
+
+/// Direction of pressure change between the oldest and newest readings.
+/// Changes under 1 hPa count as steady.
+fn pressure_trend(history: &VecDeque<(chrono::DateTime<chrono::Local>, f32)>) -> &'static str {
+    let (Some((_, oldest)), Some((_, newest))) = (history.front(), history.back()) else {
+        return "Steady →";
+    };
+    match newest - oldest {
+        change if change >= 1.0 => "Rising ↑",
+        change if change <= -1.0 => "Falling ↓",
+        _ => "Steady →",
+    }
+}
+
+/// Colour for a temperature on a blue (0°C and below) to white (20°C) to red (35°C and above) scale.
+fn temperature_color(temp_c: f32) -> cosmic::iced::Color {
+    const COLD: (f32, f32, f32) = (0.15, 0.35, 0.9);
+    const NEUTRAL: (f32, f32, f32) = (0.95, 0.95, 0.95);
+    const HOT: (f32, f32, f32) = (0.85, 0.1, 0.1);
+
+    let lerp = |from: (f32, f32, f32), to: (f32, f32, f32), t: f32| {
+        cosmic::iced::Color::from_rgb(
+            from.0 + (to.0 - from.0) * t,
+            from.1 + (to.1 - from.1) * t,
+            from.2 + (to.2 - from.2) * t,
+        )
+    };
+
+    if temp_c <= 20.0 {
+        lerp(COLD, NEUTRAL, (temp_c / 20.0).clamp(0.0, 1.0))
+    } else {
+        lerp(NEUTRAL, HOT, ((temp_c - 20.0) / 15.0).clamp(0.0, 1.0))
+    }
+}
+
+/// Wind speed, unit and compass direction, e.g. "12 mph NW".
+fn panel_wind_text(weather: &CurrentWeather, config: &Config) -> String {
+    format!(
+        "{} {} {}",
+        format_wind_speed(weather.windspeed, 0, config),
+        config.wind_speed_unit().label(),
+        wind_direction_to_compass(weather.wind_direction)
+    )
+}
+
+/// Turns a weather fetch error into a message for the popup.
+fn weather_error_message(error: &WeatherError) -> String {
+    match error {
+        WeatherError::Network(_) => crate::fl!("error-network"),
+        WeatherError::Timeout => crate::fl!("error-timeout"),
+        WeatherError::RateLimited => crate::fl!("error-rate-limited"),
+        other => other.to_string(),
+    }
+}
+
+/// Centered error message with a retry button, for tabs whose data failed to load.
+fn error_with_retry<'a>(
+    title: String,
+    error: &'a str,
+    retry_label: String,
+    on_retry: Message,
+) -> Element<'a, Message> {
+    widget::container(
+        widget::column()
+            .spacing(10)
+            .align_x(cosmic::iced::alignment::Horizontal::Center)
+            .push(
+                widget::icon::from_name("dialog-error-symbolic")
+                    .size(32)
+                    .symbolic(true),
+            )
+            .push(text(title).size(16))
+            .push(text(error).size(12))
+            .push(widget::button::standard(retry_label).on_press(on_retry)),
+    )
+    .align_x(cosmic::iced::alignment::Horizontal::Center)
+    .width(cosmic::iced::Length::Fill)
+    .into()
+}
+
+/// Small inline spinner shown while a tab's data is loading.
+fn loading_indicator<'a>(label: String) -> Element<'a, Message> {
+    widget::row()
+        .spacing(8)
+        .align_y(cosmic::iced::Alignment::Center)
+        .push(widget::icon::from_name("content-loading-symbolic").size(24))
+        .push(text(label).size(13))
+        .into()
+}
+
+/// The changelog section for `version` as plain text, falling back to the newest release
+/// if it has no entry yet. Heading markers are stripped; a bare `#123` is kept.
+fn changelog_plain_text(markdown: &str, version: &str) -> String {
+    let heading = format!("[{}]", version);
+    let mut releases = markdown
+        .split("\n## ")
+        .skip(1)
+        .filter(|section| !section.starts_with("[Unreleased]"));
+    let section = releases
+        .clone()
+        .find(|section| section.starts_with(&heading))
+        .or_else(|| releases.next())
+        .unwrap_or_default();
+    section
+        .lines()
+        // Link reference definitions trail the last release
+        .filter(|line| !(line.starts_with('[') && line.contains("]: ")))
+        .map(|line| {
+            let level = line.len() - line.trim_start_matches('#').len();
+            match line[level..].strip_prefix(' ') {
+                Some(title) if level > 0 => title,
+                _ => line,
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+        .trim_end()
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = "# Changelog\n\nPreamble text.\n\n## [Unreleased]\n\n- Pending\n\n## [1.2.0] - 2025-12-01\n\n### Added\n- Radar tab (#123)\n#42 is a bare issue reference\n\n## [1.1.0] - 2025-11-01\n\n### Fixed\n- Crash on start\n\n[1.2.0]: https://example.com/v1.2.0\n[1.1.0]: https://example.com/v1.1.0\n";
+
+    #[test]
+    fn changelog_plain_text_shows_only_the_current_release() {
+        assert_eq!(
+            changelog_plain_text(SAMPLE, "1.2.0"),
+            "[1.2.0] - 2025-12-01\n\nAdded\n- Radar tab (#123)\n#42 is a bare issue reference"
+        );
+        assert_eq!(
+            changelog_plain_text(SAMPLE, "1.1.0"),
+            "[1.1.0] - 2025-11-01\n\nFixed\n- Crash on start"
+        );
+    }
+
+    #[test]
+    fn changelog_plain_text_falls_back_to_the_newest_release() {
+        assert!(changelog_plain_text(SAMPLE, "9.9.9").starts_with("[1.2.0]"));
+        assert_eq!(changelog_plain_text("# Changelog\n", "1.0.0"), "");
+    }
+
+    #[test]
+    fn bundled_changelog_has_an_entry_for_this_version() {
+        let text = changelog_plain_text(CHANGELOG, VERSION);
+        assert!(text.starts_with(&format!("[{}]", VERSION)), "{}", text);
+    }
 }
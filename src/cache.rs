@@ -0,0 +1,206 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! On-disk cache of the last fetched weather and air quality data, so the popup
+//! has something to show immediately after a restart.
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+use crate::config::{TemperatureUnit, WindSpeedUnit};
+use crate::weather::{AirQualityData, WeatherData};
+
+const WEATHER_CACHE_FILE: &str = "weather.json";
+const AIR_QUALITY_CACHE_FILE: &str = "air_quality.json";
+
+/// Location and units a cache entry was fetched for; entries for anything else are ignored.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CacheKey {
+    pub latitude: f64,
+    pub longitude: f64,
+    pub temperature_unit: TemperatureUnit,
+    pub wind_speed_unit: WindSpeedUnit,
+}
+
+/// A cached value stored alongside the key it was fetched for. Generic over both so
+/// writes can borrow instead of cloning.
+#[derive(Serialize, Deserialize)]
+struct Keyed<K, T> {
+    key: K,
+    data: T,
+}
+
+/// Returns the applet's cache directory, creating it if needed.
+pub fn cache_dir() -> Option<PathBuf> {
+    let base = std::env::var_os("XDG_CACHE_HOME")
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".cache")))?;
+    let dir = base.join("cosmic-ext-applet-tempest");
+    std::fs::create_dir_all(&dir).ok()?;
+    Some(dir)
+}
+
+fn write_json<T: Serialize>(path: &Path, value: &T) {
+    let result = serde_json::to_vec(value)
+        .map_err(|e| e.to_string())
+        .and_then(|bytes| std::fs::write(path, bytes).map_err(|e| e.to_string()));
+    if let Err(e) = result {
+        tracing::warn!("Failed to write cache {}: {}", path.display(), e);
+    }
+}
+
+/// Reads a cached file if it was written less than `max_age` ago.
+fn read_json<T: DeserializeOwned>(path: &Path, max_age: Duration) -> Option<T> {
+    let modified = std::fs::metadata(path).ok()?.modified().ok()?;
+    let age = SystemTime::now()
+        .duration_since(modified)
+        .unwrap_or_default();
+    if age > max_age {
+        tracing::debug!("Ignoring stale cache {}", path.display());
+        return None;
+    }
+    let bytes = std::fs::read(path).ok()?;
+    match serde_json::from_slice(&bytes) {
+        Ok(value) => Some(value),
+        Err(e) => {
+            tracing::warn!("Failed to parse cache {}: {}", path.display(), e);
+            None
+        }
+    }
+}
+
+/// Writes `data` tagged with the location and units it was fetched for.
+fn write_keyed<T: Serialize>(path: &Path, key: &CacheKey, data: &T) {
+    write_json(path, &Keyed { key, data });
+}
+
+/// Reads a cached value if it is younger than `max_age` and was fetched for `key`.
+fn read_keyed<T: DeserializeOwned>(path: &Path, key: &CacheKey, max_age: Duration) -> Option<T> {
+    let cached: Keyed<CacheKey, T> = read_json(path, max_age)?;
+    if cached.key != *key {
+        tracing::debug!(
+            "Ignoring cache {} for another location or units",
+            path.display()
+        );
+        return None;
+    }
+    Some(cached.data)
+}
+
+/// Deletes the cached weather and air quality files.
+pub fn clear_cache() {
+    let Some(dir) = cache_dir() else {
+        return;
+    };
+    for file_name in [WEATHER_CACHE_FILE, AIR_QUALITY_CACHE_FILE] {
+        let path = dir.join(file_name);
+        match std::fs::remove_file(&path) {
+            Ok(()) => {}
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+            Err(e) => tracing::warn!("Failed to remove cache {}: {}", path.display(), e),
+        }
+    }
+}
+
+/// Saves the latest weather data to disk.
+pub fn write_weather_cache(key: &CacheKey, data: &WeatherData) {
+    if let Some(dir) = cache_dir() {
+        write_keyed(&dir.join(WEATHER_CACHE_FILE), key, data);
+    }
+}
+
+/// Loads cached weather data if it is younger than `max_age` and matches `key`.
+pub fn read_weather_cache(key: &CacheKey, max_age: Duration) -> Option<WeatherData> {
+    read_keyed(&cache_dir()?.join(WEATHER_CACHE_FILE), key, max_age)
+}
+
+/// Saves the latest air quality data to disk.
+pub fn write_air_quality_cache(key: &CacheKey, data: &AirQualityData) {
+    if let Some(dir) = cache_dir() {
+        write_keyed(&dir.join(AIR_QUALITY_CACHE_FILE), key, data);
+    }
+}
+
+/// Loads cached air quality data if it is younger than `max_age` and matches `key`.
+pub fn read_air_quality_cache(key: &CacheKey, max_age: Duration) -> Option<AirQualityData> {
+    read_keyed(&cache_dir()?.join(AIR_QUALITY_CACHE_FILE), key, max_age)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key() -> CacheKey {
+        CacheKey {
+            latitude: 52.52,
+            longitude: 13.405,
+            temperature_unit: TemperatureUnit::Celsius,
+            wind_speed_unit: WindSpeedUnit::KilometersPerHour,
+        }
+    }
+
+    /// A fresh file path per test so parallel tests don't share files.
+    fn temp_path(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("tempest-cache-test-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        dir.join(name)
+    }
+
+    #[test]
+    fn keyed_round_trip() {
+        let path = temp_path("round_trip.json");
+        write_keyed(&path, &key(), &vec![1, 2, 3]);
+        let cached: Option<Vec<i32>> = read_keyed(&path, &key(), Duration::from_secs(60));
+        assert_eq!(cached, Some(vec![1, 2, 3]));
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn mismatched_key_is_ignored() {
+        let path = temp_path("mismatched.json");
+        write_keyed(&path, &key(), &42);
+        let max_age = Duration::from_secs(60);
+
+        let moved = CacheKey {
+            latitude: 48.8566,
+            longitude: 2.3522,
+            ..key()
+        };
+        assert_eq!(read_keyed::<i32>(&path, &moved, max_age), None);
+        let fahrenheit = CacheKey {
+            temperature_unit: TemperatureUnit::Fahrenheit,
+            ..key()
+        };
+        assert_eq!(read_keyed::<i32>(&path, &fahrenheit, max_age), None);
+        let mph = CacheKey {
+            wind_speed_unit: WindSpeedUnit::MilesPerHour,
+            ..key()
+        };
+        assert_eq!(read_keyed::<i32>(&path, &mph, max_age), None);
+        assert_eq!(read_keyed::<i32>(&path, &key(), max_age), Some(42));
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn expired_cache_is_ignored() {
+        let path = temp_path("expired.json");
+        write_keyed(&path, &key(), &42);
+        let two_hours_ago = SystemTime::now() - Duration::from_secs(2 * 60 * 60);
+        std::fs::File::options()
+            .write(true)
+            .open(&path)
+            .and_then(|file| file.set_modified(two_hours_ago))
+            .unwrap();
+
+        assert_eq!(
+            read_keyed::<i32>(&path, &key(), Duration::from_secs(60 * 60)),
+            None
+        );
+        assert_eq!(
+            read_keyed::<i32>(&path, &key(), Duration::from_secs(3 * 60 * 60)),
+            Some(42)
+        );
+        std::fs::remove_file(path).unwrap();
+    }
+}
@@ -2,12 +2,17 @@
 
 use cosmic::cosmic_config::{self, cosmic_config_derive::CosmicConfigEntry, CosmicConfigEntry};
 use serde::{Deserialize, Serialize};
+use std::sync::OnceLock;
+
+use crate::weather::AlertSeverity;
 
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub enum TemperatureUnit {
     #[default]
     Fahrenheit,
     Celsius,
+    /// Fetched as Celsius and converted for display.
+    Kelvin,
 }
 
 impl TemperatureUnit {
@@ -16,6 +21,7 @@ impl TemperatureUnit {
         match self {
             Self::Fahrenheit => "Fahrenheit",
             Self::Celsius => "Celsius",
+            Self::Kelvin => "Kelvin",
         }
     }
 
@@ -23,22 +29,63 @@ impl TemperatureUnit {
         match self {
             Self::Fahrenheit => "°F",
             Self::Celsius => "°C",
+            Self::Kelvin => "K",
         }
     }
 
     pub fn api_param(&self) -> &'static str {
         match self {
             Self::Fahrenheit => "fahrenheit",
-            Self::Celsius => "celsius",
+            // Open-Meteo has no Kelvin option
+            Self::Celsius | Self::Kelvin => "celsius",
+        }
+    }
+
+    /// Converts a fetched temperature in this unit to Celsius.
+    pub fn to_celsius(self, temp: f32) -> f32 {
+        match self {
+            Self::Fahrenheit => (temp - 32.0) * 5.0 / 9.0,
+            Self::Celsius | Self::Kelvin => temp,
         }
     }
 
-    /// Formats a temperature value with the unit symbol.
+    /// Converts a fetched temperature in this unit to Fahrenheit.
+    pub fn to_fahrenheit(self, temp: f32) -> f32 {
+        match self {
+            Self::Fahrenheit => temp,
+            Self::Celsius | Self::Kelvin => temp * 9.0 / 5.0 + 32.0,
+        }
+    }
+
+    /// Converts a Fahrenheit temperature to this unit.
+    pub fn from_fahrenheit(self, temp_f: f32) -> f32 {
+        match self {
+            Self::Fahrenheit => temp_f,
+            Self::Celsius | Self::Kelvin => (temp_f - 32.0) * 5.0 / 9.0,
+        }
+    }
+
+    /// Formats a fetched temperature value with the unit symbol.
     pub fn format(&self, temp: f32) -> String {
-        format!("{:.0}{}", temp, self.symbol())
+        self.format_with_precision(temp, 0)
+    }
+
+    /// Formats a fetched temperature value with the unit symbol and `decimals` places.
+    pub fn format_with_precision(&self, temp: f32, decimals: u8) -> String {
+        let decimals = usize::from(decimals);
+        match self {
+            Self::Kelvin => format!("{:.*} {}", decimals, temp + 273.15, self.symbol()),
+            _ => format!("{:.*}{}", decimals, temp, self.symbol()),
+        }
     }
 }
 
+/// Selectable hourly forecast lengths in hours.
+pub const HOURLY_FORECAST_OPTIONS: [u8; 3] = [12, 24, 48];
+
+/// Selectable daily forecast lengths in days.
+pub const FORECAST_DAY_OPTIONS: [u8; 2] = [7, 14];
+
 /// Tab options for the popup interface.
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub enum PopupTab {
@@ -48,9 +95,228 @@ pub enum PopupTab {
     Alerts,
     Hourly,
     Forecast,
+    Precipitation,
+    Astronomy,
+    Solar,
+    Marine,
+    Agricultural,
     Settings,
 }
 
+/// A location saved for quick switching.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SavedLocation {
+    pub name: String,
+    pub latitude: f64,
+    pub longitude: f64,
+    /// Country name, used to pick units when switching
+    pub country: String,
+}
+
+/// An alert that has already been notified, kept until it expires.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SeenAlert {
+    pub id: String,
+    /// Unix timestamp when the alert expires
+    pub expires: i64,
+}
+
+/// Unit for atmospheric pressure displays.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PressureUnit {
+    #[default]
+    Hectopascal,
+    InchesOfMercury,
+    Millimeters,
+}
+
+impl PressureUnit {
+    /// Returns the unit symbol.
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            Self::Hectopascal => "hPa",
+            Self::InchesOfMercury => "inHg",
+            Self::Millimeters => "mmHg",
+        }
+    }
+
+    /// Converts a pressure from hPa to this unit.
+    pub fn convert(&self, hpa: f32) -> f32 {
+        match self {
+            Self::Hectopascal => hpa,
+            Self::InchesOfMercury => hpa * 0.029_53,
+            Self::Millimeters => hpa * 0.750_06,
+        }
+    }
+
+    /// Decimal places worth showing for this unit.
+    pub fn decimals(&self) -> usize {
+        match self {
+            Self::InchesOfMercury => 2,
+            _ => 0,
+        }
+    }
+
+    /// Returns the next unit, wrapping around.
+    pub fn next(self) -> Self {
+        match self {
+            Self::Hectopascal => Self::InchesOfMercury,
+            Self::InchesOfMercury => Self::Millimeters,
+            Self::Millimeters => Self::Hectopascal,
+        }
+    }
+}
+
+/// Clock style for displayed times.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TimeFormat {
+    /// Follow the system locale.
+    #[default]
+    Auto,
+    TwelveHour,
+    TwentyFourHour,
+}
+
+impl TimeFormat {
+    /// Returns the localized label for the format.
+    pub fn label(&self) -> String {
+        match self {
+            Self::Auto => crate::fl!("time-format-auto"),
+            Self::TwelveHour => crate::fl!("time-format-12-hour"),
+            Self::TwentyFourHour => crate::fl!("time-format-24-hour"),
+        }
+    }
+
+    /// Returns the next format, wrapping around.
+    pub fn next(self) -> Self {
+        match self {
+            Self::Auto => Self::TwelveHour,
+            Self::TwelveHour => Self::TwentyFourHour,
+            Self::TwentyFourHour => Self::Auto,
+        }
+    }
+
+    /// Resolves `Auto` to the clock style of the system locale.
+    pub fn resolve(self) -> Self {
+        match self {
+            Self::Auto if locale_uses_24_hour() => Self::TwentyFourHour,
+            Self::Auto => Self::TwelveHour,
+            explicit => explicit,
+        }
+    }
+
+    /// Returns true if times should use the 24-hour clock.
+    pub fn uses_24_hour(self) -> bool {
+        self.resolve() == Self::TwentyFourHour
+    }
+}
+
+/// Guesses the clock style from LC_ALL, LC_TIME or LANG, read once per process.
+/// Only a handful of locales use a 12-hour clock; unset or C locales keep 12-hour.
+fn locale_uses_24_hour() -> bool {
+    static USES_24_HOUR: OnceLock<bool> = OnceLock::new();
+    *USES_24_HOUR.get_or_init(|| {
+        let locale = ["LC_ALL", "LC_TIME", "LANG"]
+            .iter()
+            .filter_map(|var| std::env::var(var).ok())
+            .find(|value| !value.is_empty())
+            .unwrap_or_default();
+        locale_is_24_hour(&locale)
+    })
+}
+
+/// Returns true if the locale name uses the 24-hour clock.
+fn locale_is_24_hour(locale: &str) -> bool {
+    const TWELVE_HOUR_LOCALES: [&str; 9] = [
+        "en_US", "en_CA", "en_AU", "en_NZ", "en_PH", "en_IN", "hi_IN", "ar_EG", "es_MX",
+    ];
+    if locale.is_empty() || locale == "C" || locale.starts_with("C.") || locale == "POSIX" {
+        return false;
+    }
+    !TWELVE_HOUR_LOCALES
+        .iter()
+        .any(|prefix| locale.starts_with(prefix))
+}
+
+/// Source of forecast data.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum WeatherProvider {
+    #[default]
+    OpenMeteo,
+    WeatherApiCom,
+}
+
+impl WeatherProvider {
+    /// Returns the provider name.
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::OpenMeteo => "Open-Meteo",
+            Self::WeatherApiCom => "WeatherAPI.com",
+        }
+    }
+
+    /// Returns the next provider, wrapping around.
+    pub fn next(self) -> Self {
+        match self {
+            Self::OpenMeteo => Self::WeatherApiCom,
+            Self::WeatherApiCom => Self::OpenMeteo,
+        }
+    }
+}
+
+/// Unit for wind speed displays, independent of the measurement system.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum WindSpeedUnit {
+    KilometersPerHour,
+    MilesPerHour,
+    MetersPerSecond,
+    Knots,
+    Beaufort,
+}
+
+/// Wind speed units in settings cycle order.
+pub const WIND_SPEED_UNITS: [WindSpeedUnit; 5] = [
+    WindSpeedUnit::KilometersPerHour,
+    WindSpeedUnit::MilesPerHour,
+    WindSpeedUnit::MetersPerSecond,
+    WindSpeedUnit::Knots,
+    WindSpeedUnit::Beaufort,
+];
+
+impl WindSpeedUnit {
+    /// Returns the Open-Meteo `wind_speed_unit` parameter.
+    /// Beaufort isn't supported by the API, so it is fetched in km/h and converted.
+    pub fn api_param(&self) -> &'static str {
+        match self {
+            Self::KilometersPerHour | Self::Beaufort => "kmh",
+            Self::MilesPerHour => "mph",
+            Self::MetersPerSecond => "ms",
+            Self::Knots => "kn",
+        }
+    }
+
+    /// Returns the unit label.
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::KilometersPerHour => "km/h",
+            Self::MilesPerHour => "mph",
+            Self::MetersPerSecond => "m/s",
+            Self::Knots => "kn",
+            Self::Beaufort => "Bft",
+        }
+    }
+
+    /// Converts a wind speed as fetched with `api_param` to km/h.
+    pub fn to_kmh(&self, speed: f32) -> f32 {
+        match self {
+            Self::KilometersPerHour | Self::Beaufort => speed,
+            Self::MilesPerHour => speed * 1.609_34,
+            Self::MetersPerSecond => speed * 3.6,
+            Self::Knots => speed * 1.852,
+        }
+    }
+}
+
 /// Measurement system for non-temperature units (wind speed, visibility, etc.)
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub enum MeasurementSystem {
@@ -69,11 +335,11 @@ impl MeasurementSystem {
         }
     }
 
-    /// Returns the wind speed unit label.
-    pub fn wind_speed_unit(&self) -> &'static str {
+    /// Returns the wind speed unit used when none is chosen explicitly.
+    pub fn default_wind_unit(&self) -> WindSpeedUnit {
         match self {
-            Self::Imperial => "mph",
-            Self::Metric => "km/h",
+            Self::Imperial => WindSpeedUnit::MilesPerHour,
+            Self::Metric => WindSpeedUnit::KilometersPerHour,
         }
     }
 
@@ -85,11 +351,11 @@ impl MeasurementSystem {
         }
     }
 
-    /// Returns the API parameter for wind speed unit.
-    pub fn wind_speed_api_param(&self) -> &'static str {
+    /// Returns the precipitation unit label.
+    pub fn precipitation_unit(&self) -> &'static str {
         match self {
-            Self::Imperial => "mph",
-            Self::Metric => "kmh",
+            Self::Imperial => "in",
+            Self::Metric => "mm",
         }
     }
 
@@ -100,6 +366,46 @@ impl MeasurementSystem {
             Self::Metric => meters / 1000.0,
         }
     }
+
+    /// Returns the snow depth unit label.
+    pub fn snow_depth_unit(&self) -> &'static str {
+        match self {
+            Self::Imperial => "in",
+            Self::Metric => "cm",
+        }
+    }
+
+    /// Converts snow depth from meters to the appropriate unit.
+    pub fn convert_snow_depth(&self, meters: f32) -> f32 {
+        match self {
+            Self::Imperial => meters * 39.3701,
+            Self::Metric => meters * 100.0,
+        }
+    }
+
+    /// Returns the wave height unit label.
+    pub fn wave_height_unit(&self) -> &'static str {
+        match self {
+            Self::Imperial => "ft",
+            Self::Metric => "m",
+        }
+    }
+
+    /// Converts wave height from meters to the appropriate unit.
+    pub fn convert_wave_height(&self, meters: f32) -> f32 {
+        match self {
+            Self::Imperial => meters * 3.28084,
+            Self::Metric => meters,
+        }
+    }
+
+    /// Converts precipitation from millimeters to the appropriate unit.
+    pub fn convert_precipitation(&self, mm: f32) -> f32 {
+        match self {
+            Self::Imperial => mm / 25.4,
+            Self::Metric => mm,
+        }
+    }
 }
 
 #[derive(Debug, Clone, CosmicConfigEntry, PartialEq, Serialize, Deserialize)]
@@ -119,6 +425,9 @@ pub struct Config {
     pub last_updated: Option<i64>,
     /// Last selected tab, restored on popup open.
     pub default_tab: PopupTab,
+    /// Tab shown on startup. None means "remember last used" (`default_tab`).
+    #[serde(default)]
+    pub startup_tab: Option<PopupTab>,
     /// Enable weather alerts (US via NWS, EU via MeteoAlarm).
     #[serde(default = "default_alerts_enabled")]
     pub alerts_enabled: bool,
@@ -128,6 +437,331 @@ pub struct Config {
     /// Show AQI in the panel display.
     #[serde(default = "default_show_aqi_in_panel")]
     pub show_aqi_in_panel: bool,
+    /// Show the temperature label in the panel; with everything off only the icon remains.
+    #[serde(default = "default_show_temperature_in_panel")]
+    pub show_temperature_in_panel: bool,
+    /// Show current wind speed and direction in the panel display.
+    #[serde(default)]
+    pub show_wind_in_panel: bool,
+    /// Show the chance of precipitation for the next hour in the panel display.
+    #[serde(default)]
+    pub show_precip_in_panel: bool,
+    /// Cycle the panel through temperature, AQI and wind instead of showing them together.
+    #[serde(default)]
+    pub panel_rotate: bool,
+    /// Seconds each metric stays on the panel while rotating.
+    #[serde(default = "default_panel_rotate_interval_secs")]
+    pub panel_rotate_interval_secs: u64,
+    /// Panel text template; supports {temp}, {aqi}, {wind}, {precip} (next-hour chance),
+    /// {precip_amount}, {humidity} and {condition}.
+    #[serde(default = "default_panel_format")]
+    pub panel_format: String,
+    /// Show a one-line weather summary when hovering the panel button.
+    #[serde(default = "default_show_panel_tooltip")]
+    pub show_panel_tooltip: bool,
+    /// Number of hours shown in the hourly forecast (12, 24 or 48).
+    #[serde(default = "default_hourly_forecast_hours")]
+    pub hourly_forecast_hours: u8,
+    /// Number of days shown in the daily forecast (7 or 14).
+    #[serde(default = "default_forecast_days")]
+    pub forecast_days: u8,
+    /// Show relative humidity in each hourly forecast cell.
+    #[serde(default)]
+    pub show_hourly_humidity: bool,
+    /// Show feels-like high/low alongside actual temperatures in the forecast.
+    #[serde(default)]
+    pub show_daily_feels_like: bool,
+    /// Clock style for sunrise, sunset and hourly times.
+    #[serde(default)]
+    pub time_format: TimeFormat,
+    /// Decimal places shown for temperatures (0 or 1).
+    #[serde(default)]
+    pub temperature_decimal_places: u8,
+    /// Show the moon phase in the Current tab.
+    #[serde(default = "default_show_moon_phase")]
+    pub show_moon_phase: bool,
+    /// Show the Beaufort force and description after the wind speed.
+    #[serde(default)]
+    pub show_beaufort: bool,
+    /// Show fire weather danger in hot, dry conditions.
+    #[serde(default)]
+    pub show_fire_weather: bool,
+    /// Show lightning risk and CAPE when thunderstorms are around.
+    #[serde(default)]
+    pub show_convective_data: bool,
+    /// Show evapotranspiration in the forecast and the agricultural tab.
+    #[serde(default)]
+    pub show_agricultural: bool,
+    /// Show the marine (wave) forecast tab for coastal locations.
+    #[serde(default)]
+    pub marine_enabled: bool,
+    /// Fetch pollen levels for the Air Quality tab.
+    #[serde(default)]
+    pub pollen_enabled: bool,
+    /// Show the solar generation forecast tab.
+    #[serde(default)]
+    pub show_solar_tab: bool,
+    /// Rated size of the home solar system in kW.
+    #[serde(default = "default_solar_panel_kw")]
+    pub solar_panel_kw: f32,
+    /// Show a daily weather photo banner from Unsplash in the popup.
+    #[serde(default)]
+    pub show_weather_photo: bool,
+    /// Unsplash API access key for the weather photo banner.
+    #[serde(default)]
+    pub unsplash_access_key: Option<String>,
+    /// Open-Meteo commercial API key; None uses the free tier.
+    #[serde(default)]
+    pub open_meteo_api_key: Option<String>,
+    /// Base URL of a self-hosted Open-Meteo instance; None uses the public API.
+    #[serde(default)]
+    pub open_meteo_base_url: Option<String>,
+    /// Which service forecasts are fetched from.
+    #[serde(default)]
+    pub weather_provider: WeatherProvider,
+    /// WeatherAPI.com key, required when that provider is selected.
+    #[serde(default)]
+    pub weatherapi_key: Option<String>,
+    /// Elevation of the current location in meters, as reported by Open-Meteo.
+    #[serde(default)]
+    pub location_elevation_m: f32,
+    /// How many times a failed API request is attempted before giving up.
+    #[serde(default = "default_max_retry_attempts")]
+    pub max_retry_attempts: u8,
+    /// Overall timeout for API requests in seconds.
+    #[serde(default = "default_http_timeout_seconds")]
+    pub http_timeout_seconds: u64,
+    /// Timeout for establishing a connection to an API in seconds.
+    #[serde(default = "default_connect_timeout_seconds")]
+    pub connect_timeout_seconds: u64,
+    /// Minutes after which the last update is flagged as stale.
+    /// None uses twice the refresh interval.
+    #[serde(default)]
+    pub stale_threshold_minutes: Option<u64>,
+    /// Search for cities automatically after typing pauses.
+    #[serde(default)]
+    pub live_search: bool,
+    /// Locations saved for quick switching.
+    #[serde(default)]
+    pub saved_locations: Vec<SavedLocation>,
+    /// Wind speed unit. None follows the measurement system.
+    #[serde(default)]
+    pub wind_unit: Option<WindSpeedUnit>,
+    /// Unit for pressure displays.
+    #[serde(default)]
+    pub pressure_unit: PressureUnit,
+    /// Alerts already notified, so restarts don't notify them again.
+    #[serde(default)]
+    pub seen_alert_ids: Vec<SeenAlert>,
+    /// Least severe alert level that triggers a desktop notification.
+    #[serde(default = "default_min_notification_severity")]
+    pub min_notification_severity: AlertSeverity,
+    /// Popup width in pixels (380-700).
+    #[serde(default = "default_popup_width")]
+    pub popup_width: u32,
+    /// Maximum popup height in pixels (400-900).
+    #[serde(default = "default_popup_max_height")]
+    pub popup_max_height: u32,
+    /// Distance in km the device must move before auto-location updates the location.
+    #[serde(default = "default_geofence_km")]
+    pub geofence_km: f64,
+    /// Set once a location has been chosen or the first-run card dismissed.
+    #[serde(default)]
+    pub onboarding_done: bool,
+}
+
+/// Settings file written by Export Settings.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ConfigExport {
+    /// Config version the file was written with.
+    pub version: u64,
+    pub config: Config,
+}
+
+impl ConfigExport {
+    /// API keys are left out unless `include_keys` is set, since exported files
+    /// tend to get shared.
+    pub fn new(config: &Config, include_keys: bool) -> Self {
+        let mut config = config.clone();
+        if !include_keys {
+            config.open_meteo_api_key = None;
+            config.weatherapi_key = None;
+            config.unsplash_access_key = None;
+        }
+        Self {
+            version: Config::VERSION,
+            config,
+        }
+    }
+
+    /// Parses and validates an exported settings file.
+    /// Files from older versions load with defaults for newer fields; files from newer
+    /// versions are rejected since their fields may mean something different.
+    pub fn parse(contents: &[u8]) -> Result<Config, String> {
+        let value: serde_json::Value = serde_json::from_slice(contents)
+            .map_err(|e| crate::fl!("config-not-json", error = e.to_string()))?;
+        let version = value
+            .get("version")
+            .and_then(serde_json::Value::as_u64)
+            .ok_or_else(|| crate::fl!("config-not-settings-file"))?;
+        if version > Config::VERSION {
+            return Err(crate::fl!(
+                "config-newer-version",
+                version = version,
+                current = Config::VERSION
+            ));
+        }
+        let export: ConfigExport = serde_json::from_value(value)
+            .map_err(|e| crate::fl!("config-invalid", error = e.to_string()))?;
+        export.config.validate()?;
+        Ok(export.config)
+    }
+}
+
+/// A set of config changes applied together with a single save.
+/// Fields left as None are unchanged.
+#[derive(Debug, Clone, Default)]
+pub struct ConfigPatch {
+    pub latitude: Option<f64>,
+    pub longitude: Option<f64>,
+    pub location_name: Option<String>,
+    pub use_auto_location: Option<bool>,
+    /// Sets the stored manual location.
+    pub manual_latitude: Option<f64>,
+    pub manual_longitude: Option<f64>,
+    pub manual_location_name: Option<String>,
+    pub temperature_unit: Option<TemperatureUnit>,
+    pub measurement_system: Option<MeasurementSystem>,
+}
+
+impl ConfigPatch {
+    /// Returns true if the patch changes anything that requires refetching weather.
+    pub fn affects_weather(&self) -> bool {
+        self.latitude.is_some()
+            || self.longitude.is_some()
+            || self.temperature_unit.is_some()
+            || self.measurement_system.is_some()
+    }
+}
+
+impl Config {
+    /// Checks values the settings UI would never produce, such as from an imported file.
+    pub fn validate(&self) -> Result<(), String> {
+        crate::weather::validate_coordinates(self.latitude, self.longitude)
+            .map_err(|_| crate::fl!("config-invalid-coordinates"))?;
+        if !(1..=1440).contains(&self.refresh_interval_minutes) {
+            return Err(crate::fl!("config-invalid-refresh-interval"));
+        }
+        if !(380..=700).contains(&self.popup_width) {
+            return Err(crate::fl!("config-invalid-popup-width"));
+        }
+        if !(400..=900).contains(&self.popup_max_height) {
+            return Err(crate::fl!("config-invalid-popup-height"));
+        }
+        if !(1..=10).contains(&self.max_retry_attempts) {
+            return Err(crate::fl!("config-invalid-retry-attempts"));
+        }
+        if self.temperature_decimal_places > 1 {
+            return Err(crate::fl!("config-invalid-decimal-places"));
+        }
+        if !(self.geofence_km.is_finite() && (0.0..=1000.0).contains(&self.geofence_km)) {
+            return Err(crate::fl!("config-invalid-geofence"));
+        }
+        Ok(())
+    }
+
+    /// Minutes after which weather data is considered stale.
+    pub fn stale_after_minutes(&self) -> u64 {
+        self.stale_threshold_minutes
+            .unwrap_or(self.refresh_interval_minutes * 2)
+    }
+
+    /// Returns the hourly forecast length, falling back to the default if the stored value is invalid.
+    pub fn hourly_hours(&self) -> u8 {
+        if HOURLY_FORECAST_OPTIONS.contains(&self.hourly_forecast_hours) {
+            self.hourly_forecast_hours
+        } else {
+            default_hourly_forecast_hours()
+        }
+    }
+
+    /// Advances to the next hourly forecast length, wrapping around.
+    pub fn cycle_hourly_hours(&mut self) {
+        let idx = HOURLY_FORECAST_OPTIONS
+            .iter()
+            .position(|&h| h == self.hourly_hours())
+            .unwrap_or(0);
+        self.hourly_forecast_hours =
+            HOURLY_FORECAST_OPTIONS[(idx + 1) % HOURLY_FORECAST_OPTIONS.len()];
+    }
+
+    /// Returns the daily forecast length, falling back to the default if the stored value is invalid.
+    pub fn forecast_length(&self) -> u8 {
+        if FORECAST_DAY_OPTIONS.contains(&self.forecast_days) {
+            self.forecast_days
+        } else {
+            default_forecast_days()
+        }
+    }
+
+    /// Advances to the next daily forecast length, wrapping around.
+    pub fn cycle_forecast_days(&mut self) {
+        let idx = FORECAST_DAY_OPTIONS
+            .iter()
+            .position(|&d| d == self.forecast_length())
+            .unwrap_or(0);
+        self.forecast_days = FORECAST_DAY_OPTIONS[(idx + 1) % FORECAST_DAY_OPTIONS.len()];
+    }
+
+    /// Returns the wind speed unit in effect.
+    pub fn wind_speed_unit(&self) -> WindSpeedUnit {
+        self.wind_unit
+            .unwrap_or_else(|| self.measurement_system.default_wind_unit())
+    }
+
+    /// Advances to the next wind speed unit; after the last one, returns to
+    /// following the measurement system.
+    pub fn cycle_wind_unit(&mut self) {
+        self.wind_unit = match self.wind_unit {
+            None => Some(WIND_SPEED_UNITS[0]),
+            Some(unit) => WIND_SPEED_UNITS
+                .iter()
+                .position(|&u| u == unit)
+                .and_then(|idx| WIND_SPEED_UNITS.get(idx + 1))
+                .copied(),
+        };
+    }
+
+    /// Applies all set fields of the patch.
+    pub fn apply(&mut self, patch: ConfigPatch) {
+        if let Some(latitude) = patch.latitude {
+            self.latitude = latitude;
+        }
+        if let Some(longitude) = patch.longitude {
+            self.longitude = longitude;
+        }
+        if let Some(location_name) = patch.location_name {
+            self.location_name = location_name;
+        }
+        if let Some(use_auto_location) = patch.use_auto_location {
+            self.use_auto_location = use_auto_location;
+        }
+        if let Some(manual_latitude) = patch.manual_latitude {
+            self.manual_latitude = Some(manual_latitude);
+        }
+        if let Some(manual_longitude) = patch.manual_longitude {
+            self.manual_longitude = Some(manual_longitude);
+        }
+        if let Some(manual_location_name) = patch.manual_location_name {
+            self.manual_location_name = Some(manual_location_name);
+        }
+        if let Some(temperature_unit) = patch.temperature_unit {
+            self.temperature_unit = temperature_unit;
+        }
+        if let Some(measurement_system) = patch.measurement_system {
+            self.measurement_system = measurement_system;
+        }
+    }
 }
 
 fn default_alerts_enabled() -> bool {
@@ -142,6 +776,66 @@ fn default_show_aqi_in_panel() -> bool {
     true
 }
 
+fn default_show_temperature_in_panel() -> bool {
+    true
+}
+
+fn default_panel_rotate_interval_secs() -> u64 {
+    5
+}
+
+fn default_show_moon_phase() -> bool {
+    true
+}
+
+fn default_panel_format() -> String {
+    "{temp}".to_string()
+}
+
+fn default_show_panel_tooltip() -> bool {
+    true
+}
+
+fn default_hourly_forecast_hours() -> u8 {
+    24
+}
+
+fn default_forecast_days() -> u8 {
+    7
+}
+
+fn default_solar_panel_kw() -> f32 {
+    5.0
+}
+
+fn default_min_notification_severity() -> AlertSeverity {
+    AlertSeverity::Minor
+}
+
+fn default_popup_width() -> u32 {
+    440
+}
+
+fn default_popup_max_height() -> u32 {
+    550
+}
+
+fn default_geofence_km() -> f64 {
+    50.0
+}
+
+fn default_max_retry_attempts() -> u8 {
+    3
+}
+
+fn default_http_timeout_seconds() -> u64 {
+    10
+}
+
+fn default_connect_timeout_seconds() -> u64 {
+    5
+}
+
 impl Default for Config {
     fn default() -> Self {
         Self {
@@ -157,9 +851,173 @@ impl Default for Config {
             manual_location_name: None,
             last_updated: None,
             default_tab: PopupTab::default(),
+            startup_tab: None,
             alerts_enabled: true,
             auto_units: true,
             show_aqi_in_panel: true,
+            show_temperature_in_panel: true,
+            show_wind_in_panel: false,
+            show_precip_in_panel: false,
+            panel_rotate: false,
+            panel_rotate_interval_secs: default_panel_rotate_interval_secs(),
+            panel_format: default_panel_format(),
+            show_panel_tooltip: default_show_panel_tooltip(),
+            hourly_forecast_hours: default_hourly_forecast_hours(),
+            forecast_days: default_forecast_days(),
+            show_hourly_humidity: false,
+            show_daily_feels_like: false,
+            temperature_decimal_places: 0,
+            time_format: TimeFormat::default(),
+            show_moon_phase: default_show_moon_phase(),
+            show_beaufort: false,
+            show_fire_weather: false,
+            show_convective_data: false,
+            show_agricultural: false,
+            marine_enabled: false,
+            pollen_enabled: false,
+            show_solar_tab: false,
+            solar_panel_kw: default_solar_panel_kw(),
+            show_weather_photo: false,
+            unsplash_access_key: None,
+            open_meteo_api_key: None,
+            open_meteo_base_url: None,
+            weather_provider: WeatherProvider::default(),
+            weatherapi_key: None,
+            location_elevation_m: 0.0,
+            max_retry_attempts: default_max_retry_attempts(),
+            http_timeout_seconds: default_http_timeout_seconds(),
+            connect_timeout_seconds: default_connect_timeout_seconds(),
+            stale_threshold_minutes: None,
+            live_search: false,
+            saved_locations: Vec::new(),
+            wind_unit: None,
+            pressure_unit: PressureUnit::default(),
+            seen_alert_ids: Vec::new(),
+            min_notification_severity: default_min_notification_severity(),
+            popup_width: default_popup_width(),
+            popup_max_height: default_popup_max_height(),
+            geofence_km: default_geofence_km(),
+            onboarding_done: false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn export_json(config: &Config, version: u64) -> Vec<u8> {
+        let mut export = ConfigExport::new(config, true);
+        export.version = version;
+        serde_json::to_vec(&export).unwrap()
+    }
+
+    #[test]
+    fn config_export_round_trips() {
+        let config = Config {
+            latitude: 52.52,
+            longitude: 13.405,
+            location_name: "Berlin".to_string(),
+            ..Config::default()
+        };
+        let imported = ConfigExport::parse(&export_json(&config, Config::VERSION)).unwrap();
+        assert_eq!(imported, config);
+    }
+
+    #[test]
+    fn config_export_strips_keys_unless_asked() {
+        let config = Config {
+            open_meteo_api_key: Some("om-key".to_string()),
+            weatherapi_key: Some("wa-key".to_string()),
+            unsplash_access_key: Some("us-key".to_string()),
+            ..Config::default()
+        };
+        let stripped = ConfigExport::new(&config, false).config;
+        assert_eq!(stripped.open_meteo_api_key, None);
+        assert_eq!(stripped.weatherapi_key, None);
+        assert_eq!(stripped.unsplash_access_key, None);
+        let kept = ConfigExport::new(&config, true).config;
+        assert_eq!(kept, config);
+    }
+
+    #[test]
+    fn default_config_opens_on_current_tab() {
+        assert_eq!(Config::default().default_tab, PopupTab::Current);
+    }
+
+    #[test]
+    fn config_import_rejects_newer_version() {
+        let contents = export_json(&Config::default(), Config::VERSION + 1);
+        assert!(ConfigExport::parse(&contents).is_err());
+    }
+
+    #[test]
+    fn config_import_accepts_older_version() {
+        let contents = export_json(&Config::default(), 0);
+        assert!(ConfigExport::parse(&contents).is_ok());
+    }
+
+    #[test]
+    fn config_import_rejects_invalid_files() {
+        assert!(ConfigExport::parse(b"").is_err(), "empty file");
+        assert!(ConfigExport::parse(b"not json").is_err(), "not JSON");
+        assert!(ConfigExport::parse(b"{}").is_err(), "no version");
+        assert!(
+            ConfigExport::parse(br#"{"version": 1, "config": 5}"#).is_err(),
+            "config of the wrong type"
+        );
+    }
+
+    #[test]
+    fn config_import_rejects_out_of_range_values() {
+        let cases = [
+            Config {
+                latitude: 91.0,
+                ..Config::default()
+            },
+            Config {
+                refresh_interval_minutes: 0,
+                ..Config::default()
+            },
+            Config {
+                popup_width: 2000,
+                ..Config::default()
+            },
+            Config {
+                max_retry_attempts: 0,
+                ..Config::default()
+            },
+            Config {
+                max_retry_attempts: 200,
+                ..Config::default()
+            },
+            Config {
+                temperature_decimal_places: 5,
+                ..Config::default()
+            },
+            Config {
+                geofence_km: -1.0,
+                ..Config::default()
+            },
+            Config {
+                geofence_km: f64::NAN,
+                ..Config::default()
+            },
+        ];
+        for config in cases {
+            let contents = export_json(&config, Config::VERSION);
+            assert!(ConfigExport::parse(&contents).is_err(), "{:?}", config);
         }
     }
+
+    #[test]
+    fn locale_clock_style() {
+        assert!(locale_is_24_hour("de_DE.UTF-8"));
+        assert!(locale_is_24_hour("en_GB.UTF-8"));
+        assert!(!locale_is_24_hour("en_US.UTF-8"));
+        assert!(!locale_is_24_hour("en_AU"));
+        assert!(!locale_is_24_hour("C.UTF-8"));
+        assert!(!locale_is_24_hour("POSIX"));
+        assert!(!locale_is_24_hour(""));
+    }
 }
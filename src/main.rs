@@ -1,14 +1,24 @@
 // SPDX-License-Identifier: GPL-3.0-only
 
 mod applet;
+mod cache;
 mod config;
 mod i18n;
+mod photo_cache;
 mod weather;
 
+use tracing_subscriber::filter::LevelFilter;
+
 const VERSION: &str = env!("CARGO_PKG_VERSION");
 
 fn main() -> cosmic::iced::Result {
-    tracing_subscriber::fmt::init();
+    // COSMIC_APPLET_TEMPEST_DEBUG=1 enables verbose logging
+    let level = if std::env::var("COSMIC_APPLET_TEMPEST_DEBUG").as_deref() == Ok("1") {
+        LevelFilter::DEBUG
+    } else {
+        LevelFilter::INFO
+    };
+    tracing_subscriber::fmt().with_max_level(level).init();
     let _ = tracing_log::LogTracer::init();
 
     tracing::info!("Starting tempest applet v{}", VERSION);
@@ -16,5 +26,9 @@ fn main() -> cosmic::iced::Result {
     let requested_languages = i18n_embed::DesktopLanguageRequester::requested_languages();
     i18n::init(&requested_languages);
 
-    cosmic::applet::run::<applet::Tempest>(())
+    // Widget mode renders the current conditions card instead of a panel button
+    let widget_mode = std::env::args().any(|arg| arg == "--widget-mode")
+        || std::env::var("TEMPEST_WIDGET").as_deref() == Ok("1");
+
+    cosmic::applet::run::<applet::Tempest>(widget_mode)
 }
@@ -0,0 +1,110 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! Fetches the weather photo banner from Unsplash and caches it on disk for the day.
+
+use chrono::Local;
+use serde::Deserialize;
+use std::path::PathBuf;
+
+use crate::cache::cache_dir;
+use crate::weather::http_client;
+
+/// Unsplash random photo response (only the fields we use)
+#[derive(Debug, Deserialize)]
+struct UnsplashPhoto {
+    urls: UnsplashUrls,
+}
+
+#[derive(Debug, Deserialize)]
+struct UnsplashUrls {
+    raw: String,
+}
+
+/// Path of today's cached photo for a weather condition.
+fn photo_path(query: &str) -> Option<PathBuf> {
+    let slug: String = query
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() {
+                c.to_ascii_lowercase()
+            } else {
+                '-'
+            }
+        })
+        .collect();
+    let today = Local::now().format("%Y-%m-%d");
+    Some(cache_dir()?.join(format!("photo-{}-{}.jpg", today, slug)))
+}
+
+/// Removes cached photos from previous days.
+async fn prune_old_photos() {
+    let Some(dir) = cache_dir() else {
+        return;
+    };
+    let today_prefix = format!("photo-{}-", Local::now().format("%Y-%m-%d"));
+    let Ok(mut entries) = tokio::fs::read_dir(dir).await else {
+        return;
+    };
+    while let Ok(Some(entry)) = entries.next_entry().await {
+        let name = entry.file_name();
+        let name = name.to_string_lossy();
+        if name.starts_with("photo-") && !name.starts_with(&today_prefix) {
+            let _ = tokio::fs::remove_file(entry.path()).await;
+        }
+    }
+}
+
+/// Returns today's photo for the condition, fetching it from Unsplash if not cached.
+pub async fn load_weather_photo(
+    query: String,
+    access_key: String,
+) -> Result<Vec<u8>, Box<dyn std::error::Error + Send + Sync>> {
+    let path = photo_path(&query);
+
+    if let Some(ref path) = path {
+        if let Ok(bytes) = tokio::fs::read(path).await {
+            tracing::debug!("Using cached weather photo {}", path.display());
+            return Ok(bytes);
+        }
+    }
+
+    let url = format!(
+        "https://api.unsplash.com/photos/random?query={}&orientation=landscape",
+        urlencoding::encode(&query)
+    );
+    tracing::debug!("GET {}", url);
+
+    let response = http_client()
+        .get(&url)
+        .header("Authorization", format!("Client-ID {}", access_key))
+        .header("Accept-Version", "v1")
+        .send()
+        .await?;
+
+    if !response.status().is_success() {
+        return Err(format!("Unsplash API returned status: {}", response.status()).into());
+    }
+
+    let photo: UnsplashPhoto = response.json().await?;
+
+    // Ask Unsplash for a banner-sized crop (2x for HiDPI) instead of the full image
+    let image_url = format!("{}&w=840&h=200&fit=crop", photo.urls.raw);
+    tracing::debug!("GET {}", image_url);
+    let bytes = http_client()
+        .get(&image_url)
+        .send()
+        .await?
+        .error_for_status()?
+        .bytes()
+        .await?
+        .to_vec();
+
+    if let Some(ref path) = path {
+        prune_old_photos().await;
+        if let Err(e) = tokio::fs::write(path, &bytes).await {
+            tracing::warn!("Failed to cache weather photo: {}", e);
+        }
+    }
+
+    Ok(bytes)
+}
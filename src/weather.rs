@@ -2,22 +2,393 @@
 
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
-use std::sync::OnceLock;
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::sync::{Mutex, OnceLock};
+use std::time::{Duration, Instant};
+
+use crate::config::{Config, TimeFormat, WeatherProvider};
+
+pub mod providers;
 
 const USER_AGENT: &str =
     "(cosmic-ext-applet-tempest, https://github.com/VintageTechie/cosmic-ext-applet-tempest)";
 
+/// Timeouts used if the client is needed before `init_http_client` runs.
+const DEFAULT_TIMEOUT_SECONDS: u64 = 10;
+const DEFAULT_CONNECT_TIMEOUT_SECONDS: u64 = 5;
+
+static HTTP_CLIENT: OnceLock<reqwest::Client> = OnceLock::new();
+
+/// Builds an HTTP client with the given overall request and connect timeouts in seconds.
+pub fn http_client_with_config(timeout: u64, connect_timeout: u64) -> reqwest::Client {
+    reqwest::Client::builder()
+        .user_agent(USER_AGENT)
+        .timeout(Duration::from_secs(timeout.max(1)))
+        .connect_timeout(Duration::from_secs(connect_timeout.max(1)))
+        .build()
+        .expect("failed to build HTTP client")
+}
+
+/// Initializes the shared HTTP client with timeouts from the config.
+/// Only the first call takes effect, so it should run before any request is made.
+pub fn init_http_client(timeout: u64, connect_timeout: u64) {
+    if HTTP_CLIENT
+        .set(http_client_with_config(timeout, connect_timeout))
+        .is_err()
+    {
+        tracing::warn!("HTTP client already initialized; timeout settings not applied");
+    }
+}
+
 /// Shared HTTP client for connection pooling and consistent headers.
-fn http_client() -> &'static reqwest::Client {
-    static CLIENT: OnceLock<reqwest::Client> = OnceLock::new();
-    CLIENT.get_or_init(|| {
-        reqwest::Client::builder()
-            .user_agent(USER_AGENT)
-            .build()
-            .expect("failed to build HTTP client")
+pub(crate) fn http_client() -> &'static reqwest::Client {
+    HTTP_CLIENT.get_or_init(|| {
+        http_client_with_config(DEFAULT_TIMEOUT_SECONDS, DEFAULT_CONNECT_TIMEOUT_SECONDS)
     })
 }
 
+/// Errors returned by the weather, geocoding and alert fetchers.
+#[derive(Debug)]
+pub enum WeatherError {
+    /// The request could not be sent or the connection failed
+    Network(reqwest::Error),
+    /// A JSON response didn't match the expected shape
+    Decode(serde_json::Error),
+    /// An XML (Atom/CAP) response didn't match the expected shape
+    XmlDecode(quick_xml::DeError),
+    /// The service had no data for the request
+    NotFound(String),
+    /// The service rejected the request with HTTP 429
+    RateLimited,
+    /// The request took too long to complete
+    Timeout,
+    /// The service returned a 5xx status
+    Server(reqwest::StatusCode),
+    Other(String),
+}
+
+impl WeatherError {
+    /// Returns true for failures worth retrying: network errors, timeouts and 5xx responses.
+    pub fn is_transient(&self) -> bool {
+        matches!(
+            self,
+            WeatherError::Network(_) | WeatherError::Timeout | WeatherError::Server(_)
+        )
+    }
+
+    /// Maps a non-success HTTP status from a service to an error.
+    fn from_status(service: &str, status: reqwest::StatusCode) -> Self {
+        match status {
+            reqwest::StatusCode::TOO_MANY_REQUESTS => WeatherError::RateLimited,
+            reqwest::StatusCode::NOT_FOUND => {
+                WeatherError::NotFound(format!("{} returned status: {}", service, status))
+            }
+            s if s.is_server_error() => WeatherError::Server(s),
+            _ => WeatherError::Other(format!("{} returned status: {}", service, status)),
+        }
+    }
+}
+
+impl std::fmt::Display for WeatherError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WeatherError::Network(e) => write!(f, "Network error: {}", e),
+            WeatherError::Decode(e) => write!(f, "Invalid response: {}", e),
+            WeatherError::XmlDecode(e) => write!(f, "Invalid XML response: {}", e),
+            WeatherError::NotFound(msg) => write!(f, "{}", msg),
+            WeatherError::RateLimited => write!(f, "Rate limited by the weather service"),
+            WeatherError::Timeout => write!(f, "Request timed out"),
+            WeatherError::Server(status) => write!(f, "Weather service error: {}", status),
+            WeatherError::Other(msg) => write!(f, "{}", msg),
+        }
+    }
+}
+
+impl std::error::Error for WeatherError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            WeatherError::Network(e) => Some(e),
+            WeatherError::Decode(e) => Some(e),
+            WeatherError::XmlDecode(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<reqwest::Error> for WeatherError {
+    fn from(e: reqwest::Error) -> Self {
+        if e.is_timeout() {
+            WeatherError::Timeout
+        } else if e.status() == Some(reqwest::StatusCode::TOO_MANY_REQUESTS) {
+            WeatherError::RateLimited
+        } else {
+            WeatherError::Network(e)
+        }
+    }
+}
+
+impl From<serde_json::Error> for WeatherError {
+    fn from(e: serde_json::Error) -> Self {
+        WeatherError::Decode(e)
+    }
+}
+
+impl From<quick_xml::DeError> for WeatherError {
+    fn from(e: quick_xml::DeError) -> Self {
+        WeatherError::XmlDecode(e)
+    }
+}
+
+impl From<String> for WeatherError {
+    fn from(msg: String) -> Self {
+        WeatherError::Other(msg)
+    }
+}
+
+impl From<&str> for WeatherError {
+    fn from(msg: &str) -> Self {
+        WeatherError::Other(msg.to_string())
+    }
+}
+
+/// Base delay between retries; doubles with each attempt.
+const RETRY_BASE_DELAY_MS: u64 = 500;
+
+/// Upper bound on a single backoff delay, jitter included.
+const RETRY_MAX_DELAY_MS: u64 = 30_000;
+
+/// Allowed range for the configured number of attempts.
+const RETRY_ATTEMPTS_RANGE: std::ops::RangeInclusive<u8> = 1..=10;
+
+static MAX_RETRY_ATTEMPTS: AtomicU8 = AtomicU8::new(3);
+
+/// Sets how many attempts the fetch functions make before giving up, clamped to 1–10.
+pub fn set_max_retry_attempts(attempts: u8) {
+    let attempts = attempts.clamp(*RETRY_ATTEMPTS_RANGE.start(), *RETRY_ATTEMPTS_RANGE.end());
+    MAX_RETRY_ATTEMPTS.store(attempts, Ordering::Relaxed);
+}
+
+fn max_retry_attempts() -> u8 {
+    MAX_RETRY_ATTEMPTS.load(Ordering::Relaxed)
+}
+
+/// Host and optional commercial key for one Open-Meteo API, resolved from the config.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OpenMeteoApi {
+    pub base_url: String,
+    pub api_key: Option<String>,
+}
+
+impl OpenMeteoApi {
+    /// Forecast API endpoint for the configured instance and tier.
+    pub fn forecast(config: &Config) -> Self {
+        Self::new(config, weather_api_base(config))
+    }
+
+    /// Air Quality API endpoint for the configured instance and tier.
+    pub fn air_quality(config: &Config) -> Self {
+        Self::new(config, api_base(config, open_meteo_air_quality_base_url))
+    }
+
+    /// Marine API endpoint for the configured instance and tier.
+    pub fn marine(config: &Config) -> Self {
+        Self::new(config, api_base(config, open_meteo_marine_base_url))
+    }
+
+    fn new(config: &Config, base_url: &str) -> Self {
+        Self {
+            base_url: base_url.to_string(),
+            api_key: open_meteo_api_key(config).map(str::to_string),
+        }
+    }
+}
+
+/// The configured Open-Meteo commercial API key; None uses the free tier.
+fn open_meteo_api_key(config: &Config) -> Option<&str> {
+    config
+        .open_meteo_api_key
+        .as_deref()
+        .map(str::trim)
+        .filter(|key| !key.is_empty())
+}
+
+/// Forecast API base URL: the self-hosted instance if one is configured, otherwise
+/// the public or commercial host.
+pub fn weather_api_base(config: &Config) -> &str {
+    api_base(config, open_meteo_base_url)
+}
+
+/// Returns the self-hosted instance URL if one is configured, otherwise the tier's
+/// public host. A self-hosted instance serves every API (forecast, air quality, marine)
+/// from one host.
+fn api_base(config: &Config, public_base: fn(Option<&str>) -> &'static str) -> &str {
+    config
+        .open_meteo_base_url
+        .as_deref()
+        .map(|url| url.trim().trim_end_matches('/'))
+        .filter(|url| !url.is_empty())
+        .unwrap_or_else(|| public_base(open_meteo_api_key(config)))
+}
+
+/// Forecast API base URL; the commercial tier is served from its own host.
+fn open_meteo_base_url(key: Option<&str>) -> &'static str {
+    match key {
+        Some(_) => "https://customer-api.open-meteo.com",
+        None => "https://api.open-meteo.com",
+    }
+}
+
+/// Air Quality API base URL for the free or commercial tier.
+fn open_meteo_air_quality_base_url(key: Option<&str>) -> &'static str {
+    match key {
+        Some(_) => "https://customer-air-quality-api.open-meteo.com",
+        None => "https://air-quality-api.open-meteo.com",
+    }
+}
+
+/// Marine API base URL for the free or commercial tier.
+fn open_meteo_marine_base_url(key: Option<&str>) -> &'static str {
+    match key {
+        Some(_) => "https://customer-marine-api.open-meteo.com",
+        None => "https://marine-api.open-meteo.com",
+    }
+}
+
+/// Builds a GET request to Open-Meteo, adding the API key when one is set.
+/// The key is added here rather than in the URL so it never shows up in debug logs.
+fn open_meteo_get(url: &str, key: Option<&str>) -> reqwest::RequestBuilder {
+    let request = http_client().get(url);
+    match key {
+        Some(key) => request.query(&[("apikey", key)]),
+        None => request,
+    }
+}
+
+/// Timeout for the connectivity probe sent before each refresh.
+const CONNECTIVITY_TIMEOUT: Duration = Duration::from_secs(3);
+
+/// Checks that the selected provider's forecast API is reachable with a quick HEAD
+/// request. Any HTTP response counts; only connection failures and timeouts mean offline.
+pub async fn check_connectivity(provider: WeatherProvider, open_meteo: &OpenMeteoApi) -> bool {
+    let url = match provider {
+        WeatherProvider::OpenMeteo => open_meteo.base_url.as_str(),
+        WeatherProvider::WeatherApiCom => providers::weatherapi::BASE_URL,
+    };
+    http_client()
+        .head(url)
+        .timeout(CONNECTIVITY_TIMEOUT)
+        .send()
+        .await
+        .is_ok()
+}
+
+/// Runs `f` until it succeeds, retrying transient errors with jittered exponential
+/// backoff (base × 2^attempt plus up to `base_delay_ms` of jitter), capped at 30 s.
+pub async fn retry_with_backoff<F, Fut, T>(
+    mut f: F,
+    max_attempts: u8,
+    base_delay_ms: u64,
+) -> Result<T, WeatherError>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, WeatherError>>,
+{
+    let max_attempts = max_attempts.max(1);
+    let mut attempt: u8 = 0;
+    loop {
+        match f().await {
+            Ok(value) => return Ok(value),
+            Err(e) if e.is_transient() && attempt + 1 < max_attempts => {
+                // No rand dependency; the clock's sub-second nanos are random enough for jitter
+                let nanos = std::time::SystemTime::now()
+                    .duration_since(std::time::UNIX_EPOCH)
+                    .map(|d| u64::from(d.subsec_nanos()))
+                    .unwrap_or(0);
+                let jitter = nanos % (base_delay_ms + 1);
+                let delay = backoff_delay_ms(base_delay_ms, attempt, jitter);
+                attempt += 1;
+                tracing::warn!(
+                    "Request failed ({}), retrying in {} ms (attempt {}/{})",
+                    e,
+                    delay,
+                    attempt + 1,
+                    max_attempts
+                );
+                tokio::time::sleep(Duration::from_millis(delay)).await;
+            }
+            Err(e) => return Err(e),
+        }
+    }
+}
+
+/// Delay before retry number `attempt + 1`; saturates instead of overflowing.
+fn backoff_delay_ms(base_delay_ms: u64, attempt: u8, jitter: u64) -> u64 {
+    let factor = 1u64 << u32::from(attempt).min(20);
+    base_delay_ms
+        .saturating_mul(factor)
+        .saturating_add(jitter)
+        .min(RETRY_MAX_DELAY_MS)
+}
+
+/// Checks the response status and decodes its JSON body.
+async fn read_json<T: serde::de::DeserializeOwned>(
+    service: &str,
+    response: reqwest::Response,
+) -> Result<T, WeatherError> {
+    if !response.status().is_success() {
+        return Err(WeatherError::from_status(service, response.status()));
+    }
+    let body = response.text().await?;
+    Ok(serde_json::from_str(&body)?)
+}
+
+/// Outcome of a request made with a stored ETag.
+#[derive(Debug, Clone)]
+pub enum Conditional<T> {
+    /// New data, with the ETag to send next time if the server gave one
+    Modified(T, Option<String>),
+    /// The server answered 304: the data from the last fetch is still current
+    NotModified,
+}
+
+/// Like `read_json`, but a 304 reply to `If-None-Match` yields `NotModified`.
+async fn read_json_conditional<T: serde::de::DeserializeOwned>(
+    service: &str,
+    response: reqwest::Response,
+) -> Result<Conditional<T>, WeatherError> {
+    if response.status() == reqwest::StatusCode::NOT_MODIFIED {
+        return Ok(Conditional::NotModified);
+    }
+    let etag = response
+        .headers()
+        .get(reqwest::header::ETAG)
+        .and_then(|value| value.to_str().ok())
+        .map(str::to_string);
+    Ok(Conditional::Modified(
+        read_json(service, response).await?,
+        etag,
+    ))
+}
+
+/// Adds `If-None-Match` when there's an ETag from a previous response.
+fn with_etag(request: reqwest::RequestBuilder, etag: Option<&str>) -> reqwest::RequestBuilder {
+    match etag {
+        Some(etag) => request.header(reqwest::header::IF_NONE_MATCH, etag),
+        None => request,
+    }
+}
+
+/// How long ECCC directory listings are reused before being fetched again.
+const ECCC_LISTING_TTL: Duration = Duration::from_secs(15 * 60);
+
+/// Cache of ECCC directory listing HTML keyed by URL, with the time it was fetched.
+fn eccc_listing_cache() -> &'static Mutex<HashMap<String, (String, Instant)>> {
+    static CACHE: OnceLock<Mutex<HashMap<String, (String, Instant)>>> = OnceLock::new();
+    CACHE.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
 /// Current weather conditions
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CurrentWeather {
@@ -26,12 +397,26 @@ pub struct CurrentWeather {
     pub windspeed: f32,
     pub humidity: i32,
     pub feels_like: f32,
+    pub dewpoint: f32,
     pub wind_direction: i32,
     pub wind_gusts: f32,
     pub uv_index: f32,
     pub visibility: f32,
     pub pressure: f32,
     pub cloud_cover: i32,
+    /// Total precipitation over the preceding hour in mm
+    pub precipitation: f32,
+    /// Rain from large-scale systems in mm
+    pub rain: f32,
+    /// Rain from convective showers in mm
+    pub showers: f32,
+    /// Snowfall in cm
+    pub snowfall: f32,
+    /// Convective available potential energy in J/kg
+    pub cape: f32,
+    /// Lightning potential index in J/kg; only the ICON-D2 model over central Europe has it
+    #[serde(default)]
+    pub lightning_potential: Option<f32>,
 }
 
 /// Daily forecast data
@@ -40,9 +425,23 @@ pub struct DailyForecast {
     pub date: String,
     pub temp_max: f32,
     pub temp_min: f32,
+    pub apparent_temperature_max: f32,
+    pub apparent_temperature_min: f32,
+    /// Total precipitation for the day in mm
+    pub precipitation_sum: f32,
+    pub precipitation_probability_max: i32,
+    pub uv_index_max: f32,
     pub weathercode: i32,
     pub sunrise: String,
     pub sunset: String,
+    /// Midpoint between sunrise and sunset, in the same format
+    pub solar_noon: String,
+    /// Maximum snow depth for the day in meters, None when there's no snow
+    pub snow_depth: Option<f32>,
+    /// FAO reference evapotranspiration (ET0) in mm
+    pub et0_evapotranspiration: f32,
+    /// Mean surface soil temperature for the day, None when unavailable
+    pub soil_temperature: Option<f32>,
 }
 
 /// Hourly forecast data
@@ -52,21 +451,34 @@ pub struct HourlyForecast {
     pub temperature: f32,
     pub weathercode: i32,
     pub precipitation_probability: i32,
+    /// Precipitation over the hour in mm
+    pub precipitation: f32,
+    pub humidity: i32,
+    pub windspeed: f32,
+    pub wind_direction: i32,
+    /// Direct solar radiation on a horizontal surface in W/m²
+    pub direct_radiation: f32,
+    /// Diffuse (scattered) solar radiation in W/m²
+    pub diffuse_radiation: f32,
 }
 
 /// Complete weather data
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WeatherData {
     pub current: CurrentWeather,
     pub hourly: Vec<HourlyForecast>,
     pub forecast: Vec<DailyForecast>,
+    /// Elevation of the forecast location in meters, when the provider reports it
+    pub elevation: Option<f32>,
 }
 
 /// AQI standard based on region
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum AqiStandard {
     Us,
     European,
+    /// Canadian Air Quality Health Index
+    Canada,
 }
 
 /// Geographic region for alert provider and AQI standard selection
@@ -75,11 +487,13 @@ pub enum Region {
     Us,
     Europe,
     Canada,
+    Australia,
+    Asia,
     Unknown,
 }
 
 /// Current air quality data
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AirQualityData {
     pub aqi: i32,
     pub standard: AqiStandard,
@@ -88,10 +502,47 @@ pub struct AirQualityData {
     pub ozone: f32,
     pub nitrogen_dioxide: f32,
     pub carbon_monoxide: f32,
+    /// Hourly (time, AQI) forecast for the next 24 hours in the same standard
+    pub hourly_aqi: Vec<(String, i32)>,
+}
+
+/// Current pollen concentrations in grains/m³ (Open-Meteo covers Europe only).
+/// `None` means the species isn't reported here or is out of season.
+#[derive(Debug, Clone)]
+pub struct PollenData {
+    pub alder: Option<f32>,
+    pub birch: Option<f32>,
+    pub grass: Option<f32>,
+    pub mugwort: Option<f32>,
+    pub olive: Option<f32>,
+    pub ragweed: Option<f32>,
+}
+
+/// Current sea state from the Open-Meteo Marine API; heights in meters, periods in seconds
+#[derive(Debug, Clone)]
+pub struct MarineData {
+    pub wave_height: f32,
+    pub wave_direction: i32,
+    pub wave_period: f32,
+    pub swell_wave_height: f32,
+    pub swell_wave_direction: i32,
+    pub wind_wave_height: f32,
+}
+
+/// Solar irradiance forecast for estimating home solar generation
+#[derive(Debug, Clone)]
+pub struct SolarForecastData {
+    /// Hourly (time, shortwave radiation in W/m²) pairs for today
+    pub hourly: Vec<(String, f32)>,
+    /// Total insolation for today in kWh/m² (equivalent to peak sun hours)
+    pub total_kwh_today: f32,
 }
 
+/// Fraction of rated panel output actually delivered (inverter, wiring, heat and soiling losses).
+const SOLAR_PERFORMANCE_RATIO: f32 = 0.75;
+
 /// Weather alert severity levels
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum AlertSeverity {
     Minor,
     Moderate,
@@ -112,6 +563,46 @@ impl AlertSeverity {
             _ => Self::Unknown,
         }
     }
+
+    /// Next notification threshold: all alerts, Moderate+, Severe+, Extreme only.
+    pub fn next_threshold(self) -> Self {
+        match self {
+            Self::Minor | Self::Unknown => Self::Moderate,
+            Self::Moderate => Self::Severe,
+            Self::Severe => Self::Extreme,
+            Self::Extreme => Self::Minor,
+        }
+    }
+
+    /// Returns true if an alert of this severity passes a notification threshold.
+    /// A Minor threshold means all alerts, including those of unknown severity.
+    pub fn meets_threshold(self, threshold: Self) -> bool {
+        threshold == Self::Minor || self <= threshold
+    }
+
+    /// Sort priority, most urgent first.
+    fn priority(self) -> u8 {
+        match self {
+            Self::Extreme => 0,
+            Self::Severe => 1,
+            Self::Moderate => 2,
+            Self::Minor => 3,
+            Self::Unknown => 4,
+        }
+    }
+}
+
+/// Orders by urgency, so the most severe alert sorts first.
+impl Ord for AlertSeverity {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.priority().cmp(&other.priority())
+    }
+}
+
+impl PartialOrd for AlertSeverity {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
 }
 
 /// Weather alert from NWS or other sources.
@@ -128,9 +619,20 @@ pub struct Alert {
     pub instruction: Option<String>,
     pub area_desc: String,
     pub sent: DateTime<Utc>,
+    /// When the alert takes effect, if it differs from when it was sent.
+    pub onset: Option<DateTime<Utc>>,
+    /// CAP certainty: Observed, Likely, Possible or Unlikely
+    pub certainty: Option<String>,
     pub expires: DateTime<Utc>,
 }
 
+impl Alert {
+    /// Returns true if the alert has an onset time that hasn't been reached yet.
+    pub fn is_future(&self) -> bool {
+        self.onset.is_some_and(|onset| onset > Utc::now())
+    }
+}
+
 /// NWS API GeoJSON response structure
 #[derive(Debug, Deserialize)]
 struct NwsAlertsResponse {
@@ -154,6 +656,9 @@ struct NwsAlertProperties {
     instruction: Option<String>,
     area_desc: String,
     sent: String,
+    onset: Option<String>,
+    effective: Option<String>,
+    certainty: Option<String>,
     expires: Option<String>,
 }
 
@@ -182,10 +687,14 @@ struct MeteoAlarmEntry {
     cap_area_desc: Option<String>,
     #[serde(rename = "sent")]
     cap_sent: Option<String>,
+    #[serde(rename = "onset")]
+    cap_onset: Option<String>,
     #[serde(rename = "expires")]
     cap_expires: Option<String>,
     #[serde(rename = "effective")]
     cap_effective: Option<String>,
+    #[serde(rename = "certainty")]
+    cap_certainty: Option<String>,
     /// Geocode containing EMMA_ID for region filtering
     #[serde(rename = "geocode")]
     cap_geocode: Option<MeteoAlarmGeocode>,
@@ -223,6 +732,7 @@ struct EcccCapInfo {
     severity: Option<String>,
     certainty: Option<String>,
     effective: Option<String>,
+    onset: Option<String>,
     expires: Option<String>,
     headline: Option<String>,
     description: Option<String>,
@@ -240,6 +750,30 @@ struct EcccCapArea {
     polygon: Option<String>,
 }
 
+/// RSS 2.0 feed, as published by the Bureau of Meteorology
+#[derive(Debug, Deserialize)]
+struct RssFeed {
+    channel: RssChannel,
+}
+
+/// Channel element of an RSS feed
+#[derive(Debug, Deserialize)]
+struct RssChannel {
+    #[serde(rename = "item", default)]
+    items: Vec<RssItem>,
+}
+
+/// Single item from an RSS feed
+#[derive(Debug, Deserialize)]
+struct RssItem {
+    title: Option<String>,
+    link: Option<String>,
+    guid: Option<String>,
+    description: Option<String>,
+    #[serde(rename = "pubDate")]
+    pub_date: Option<String>,
+}
+
 /// Nominatim reverse geocoding response
 #[derive(Debug, Deserialize)]
 struct NominatimResponse {
@@ -257,6 +791,9 @@ struct NominatimAddress {
     municipality: Option<String>,
     county: Option<String>,
     state: Option<String>,
+    country: Option<String>,
+    /// ISO 3166-1 alpha-2 code in lowercase ("de")
+    country_code: Option<String>,
     #[serde(rename = "ISO3166-2-lvl4")]
     iso_state: Option<String>,
 }
@@ -269,109 +806,324 @@ struct MeteoAlarmCodenames {
 }
 
 /// Open-Meteo API response structure
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Deserialize)]
 struct OpenMeteoResponse {
+    #[serde(default)]
+    elevation: f32,
+    #[serde(default)]
+    utc_offset_seconds: i32,
     current: CurrentData,
     hourly: HourlyData,
     daily: DailyData,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Deserialize)]
 struct CurrentData {
+    time: String,
     temperature_2m: f32,
     weathercode: i32,
     windspeed_10m: f32,
     relative_humidity_2m: i32,
     apparent_temperature: f32,
+    #[serde(default)]
+    dewpoint_2m: Option<f32>,
     wind_direction_10m: i32,
     wind_gusts_10m: f32,
     uv_index: f32,
     visibility: f32,
     surface_pressure: f32,
     cloud_cover: i32,
+    precipitation: f32,
+    rain: f32,
+    showers: f32,
+    snowfall: f32,
+    #[serde(default)]
+    cape: Option<f32>,
+    /// Null outside the ICON-D2 domain
+    #[serde(default)]
+    lightning_potential: Option<f32>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Deserialize)]
 struct HourlyData {
     time: Vec<String>,
     temperature_2m: Vec<f32>,
     weathercode: Vec<i32>,
     precipitation_probability: Vec<i32>,
+    precipitation: Vec<f32>,
+    relative_humidity_2m: Vec<i32>,
+    windspeed_10m: Vec<f32>,
+    winddirection_10m: Vec<i32>,
+    snow_depth: Vec<Option<f32>>,
+    soil_temperature_0cm: Vec<Option<f32>>,
+    direct_radiation: Vec<Option<f32>>,
+    diffuse_radiation: Vec<Option<f32>>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Deserialize)]
 struct DailyData {
     time: Vec<String>,
     temperature_2m_max: Vec<f32>,
     temperature_2m_min: Vec<f32>,
+    apparent_temperature_max: Vec<f32>,
+    apparent_temperature_min: Vec<f32>,
+    precipitation_sum: Vec<f32>,
+    precipitation_probability_max: Vec<i32>,
+    uv_index_max: Vec<f32>,
     weathercode: Vec<i32>,
     sunrise: Vec<String>,
     sunset: Vec<String>,
+    et0_fao_evapotranspiration: Vec<f32>,
+}
+
+/// The last forecast response with the URL and ETag it was fetched with. A 304 only
+/// confirms the raw data; the hourly window still has to be cut from it again.
+fn forecast_response_cache() -> &'static Mutex<Option<(String, String, OpenMeteoResponse)>> {
+    static CACHE: OnceLock<Mutex<Option<(String, String, OpenMeteoResponse)>>> = OnceLock::new();
+    CACHE.get_or_init(|| Mutex::new(None))
 }
 
-/// Fetches weather data from Open-Meteo API
+/// Fetches weather data from Open-Meteo API.
+/// With an ETag from the previous fetch, an unchanged forecast is rebuilt from the stored
+/// response so the hourly window follows the clock and the configured length. It only
+/// comes back as `NotModified` if that response is no longer around.
+#[allow(clippy::too_many_arguments)]
 pub async fn fetch_weather(
+    api: &OpenMeteoApi,
     latitude: f64,
     longitude: f64,
     temperature_unit: &str,
     windspeed_unit: &str,
-) -> Result<WeatherData, Box<dyn std::error::Error>> {
-    let url = format!(
-        "https://api.open-meteo.com/v1/forecast?latitude={}&longitude={}&current=temperature_2m,weathercode,windspeed_10m,relative_humidity_2m,apparent_temperature,wind_direction_10m,wind_gusts_10m,uv_index,visibility,surface_pressure,cloud_cover&hourly=temperature_2m,weathercode,precipitation_probability&daily=temperature_2m_max,temperature_2m_min,weathercode,sunrise,sunset&temperature_unit={}&windspeed_unit={}&timezone=auto&forecast_days=7&forecast_hours=24",
-        latitude, longitude, temperature_unit, windspeed_unit
+    hourly_hours: usize,
+    forecast_days: u8,
+    etag: Option<&str>,
+) -> Result<Conditional<WeatherData>, WeatherError> {
+    let url = forecast_url(
+        &api.base_url,
+        latitude,
+        longitude,
+        temperature_unit,
+        windspeed_unit,
+        forecast_days,
     );
+    fetch_forecast(
+        &url,
+        api.api_key.as_deref(),
+        temperature_unit,
+        hourly_hours,
+        etag,
+    )
+    .await
+}
 
-    let response = http_client().get(&url).send().await?;
-    let data: OpenMeteoResponse = response.json().await?;
+/// Builds the forecast request URL against an Open-Meteo host.
+fn forecast_url(
+    base_url: &str,
+    latitude: f64,
+    longitude: f64,
+    temperature_unit: &str,
+    windspeed_unit: &str,
+    forecast_days: u8,
+) -> String {
+    format!(
+        "{}/v1/forecast?latitude={}&longitude={}&current=temperature_2m,weathercode,windspeed_10m,relative_humidity_2m,apparent_temperature,dewpoint_2m,wind_direction_10m,wind_gusts_10m,uv_index,visibility,surface_pressure,cloud_cover,precipitation,rain,showers,snowfall,cape,lightning_potential&hourly=temperature_2m,weathercode,precipitation_probability,precipitation,relative_humidity_2m,windspeed_10m,winddirection_10m,snow_depth,soil_temperature_0cm,direct_radiation,diffuse_radiation&daily=temperature_2m_max,temperature_2m_min,apparent_temperature_max,apparent_temperature_min,precipitation_sum,precipitation_probability_max,uv_index_max,weathercode,sunrise,sunset,et0_fao_evapotranspiration&temperature_unit={}&windspeed_unit={}&timezone=auto&forecast_days={}",
+        base_url,
+        latitude,
+        longitude,
+        temperature_unit,
+        windspeed_unit,
+        forecast_days
+    )
+}
+
+/// Requests a forecast URL, reusing the stored response when the server answers 304.
+async fn fetch_forecast(
+    url: &str,
+    api_key: Option<&str>,
+    temperature_unit: &str,
+    hourly_hours: usize,
+    etag: Option<&str>,
+) -> Result<Conditional<WeatherData>, WeatherError> {
+    tracing::debug!("GET {}", url);
+
+    let response: Conditional<OpenMeteoResponse> = retry_with_backoff(
+        || async move {
+            let response = with_etag(open_meteo_get(url, api_key), etag).send().await?;
+            read_json_conditional("Open-Meteo", response).await
+        },
+        max_retry_attempts(),
+        RETRY_BASE_DELAY_MS,
+    )
+    .await?;
+    match response {
+        Conditional::Modified(data, etag) => {
+            let weather = build_weather_data(&data, temperature_unit, hourly_hours, false);
+            if let Some(etag) = &etag {
+                *forecast_response_cache()
+                    .lock()
+                    .unwrap_or_else(|e| e.into_inner()) =
+                    Some((url.to_string(), etag.clone(), data));
+            }
+            Ok(Conditional::Modified(weather, etag))
+        }
+        Conditional::NotModified => {
+            let cache = forecast_response_cache()
+                .lock()
+                .unwrap_or_else(|e| e.into_inner());
+            match cache.as_ref() {
+                Some((cached_url, cached_etag, data))
+                    if cached_url == url && Some(cached_etag.as_str()) == etag =>
+                {
+                    let weather = build_weather_data(data, temperature_unit, hourly_hours, true);
+                    Ok(Conditional::Modified(weather, Some(cached_etag.clone())))
+                }
+                _ => Ok(Conditional::NotModified),
+            }
+        }
+    }
+}
 
-    // Process hourly forecast (limit to 12 hours)
+/// Converts an Open-Meteo response into `WeatherData`, keeping `hourly_hours` of the
+/// hourly forecast. A `revalidated` response may be hours old, so its hourly window
+/// starts at the current local time rather than the time the data was fetched.
+fn build_weather_data(
+    data: &OpenMeteoResponse,
+    temperature_unit: &str,
+    hourly_hours: usize,
+    revalidated: bool,
+) -> WeatherData {
+    // Hourly data covers every forecast day from midnight (snow depth needs whole days),
+    // so the hourly forecast starts at the current hour
+    let hour_position = |hour: &str| data.hourly.time.iter().position(|t| t.as_str() >= hour);
+    let current_hour = data.current.time.get(..13).unwrap_or_default();
+    let local_now = (Utc::now() + chrono::Duration::seconds(i64::from(data.utc_offset_seconds)))
+        .format("%Y-%m-%dT%H")
+        .to_string();
+    let start = revalidated
+        .then(|| hour_position(local_now.as_str().max(current_hour)))
+        .flatten()
+        .or_else(|| hour_position(current_hour))
+        .unwrap_or(0);
+
+    // Process hourly forecast (limited to the configured number of hours)
     let mut hourly = Vec::new();
-    for i in 0..data.hourly.time.len().min(12) {
+    for i in start..data.hourly.time.len().min(start + hourly_hours) {
         hourly.push(HourlyForecast {
             time: data.hourly.time[i].clone(),
             temperature: data.hourly.temperature_2m[i],
             weathercode: data.hourly.weathercode[i],
             precipitation_probability: data.hourly.precipitation_probability[i],
+            precipitation: data.hourly.precipitation[i],
+            humidity: data.hourly.relative_humidity_2m[i],
+            windspeed: data.hourly.windspeed_10m[i],
+            wind_direction: data.hourly.winddirection_10m[i],
+            direct_radiation: data.hourly.direct_radiation[i].unwrap_or(0.0),
+            diffuse_radiation: data.hourly.diffuse_radiation[i].unwrap_or(0.0),
         });
     }
 
     // Process daily forecast
     let mut forecast = Vec::new();
     for i in 0..data.daily.time.len() {
+        // Open-Meteo only provides snow depth hourly; use the day's maximum
+        let snow_depth = data
+            .hourly
+            .time
+            .iter()
+            .zip(&data.hourly.snow_depth)
+            .filter(|(time, _)| time.starts_with(&data.daily.time[i]))
+            .filter_map(|(_, depth)| *depth)
+            .fold(0.0_f32, f32::max);
+
+        let soil_temps: Vec<f32> = data
+            .hourly
+            .time
+            .iter()
+            .zip(&data.hourly.soil_temperature_0cm)
+            .filter(|(time, _)| time.starts_with(&data.daily.time[i]))
+            .filter_map(|(_, temp)| *temp)
+            .collect();
+        let soil_temperature = (!soil_temps.is_empty())
+            .then(|| soil_temps.iter().sum::<f32>() / soil_temps.len() as f32);
+
         forecast.push(DailyForecast {
             date: data.daily.time[i].clone(),
             temp_max: data.daily.temperature_2m_max[i],
             temp_min: data.daily.temperature_2m_min[i],
+            apparent_temperature_max: data.daily.apparent_temperature_max[i],
+            apparent_temperature_min: data.daily.apparent_temperature_min[i],
+            precipitation_sum: data.daily.precipitation_sum[i],
+            precipitation_probability_max: data.daily.precipitation_probability_max[i],
+            uv_index_max: data.daily.uv_index_max[i],
             weathercode: data.daily.weathercode[i],
             sunrise: data.daily.sunrise[i].clone(),
             sunset: data.daily.sunset[i].clone(),
+            solar_noon: solar_noon(&data.daily.sunrise[i], &data.daily.sunset[i]),
+            snow_depth: (snow_depth > 0.0).then_some(snow_depth),
+            et0_evapotranspiration: data.daily.et0_fao_evapotranspiration[i],
+            soil_temperature,
         });
     }
 
-    Ok(WeatherData {
+    WeatherData {
         current: CurrentWeather {
             temperature: data.current.temperature_2m,
             weathercode: data.current.weathercode,
             windspeed: data.current.windspeed_10m,
             humidity: data.current.relative_humidity_2m,
             feels_like: data.current.apparent_temperature,
+            dewpoint: data.current.dewpoint_2m.unwrap_or_else(|| {
+                // Fall back to computing it; Magnus works in °C
+                let fahrenheit = temperature_unit == "fahrenheit";
+                let temp_c = if fahrenheit {
+                    (data.current.temperature_2m - 32.0) * 5.0 / 9.0
+                } else {
+                    data.current.temperature_2m
+                };
+                let dewpoint_c = magnus_dewpoint(temp_c, data.current.relative_humidity_2m);
+                if fahrenheit {
+                    dewpoint_c * 9.0 / 5.0 + 32.0
+                } else {
+                    dewpoint_c
+                }
+            }),
             wind_direction: data.current.wind_direction_10m,
             wind_gusts: data.current.wind_gusts_10m,
             uv_index: data.current.uv_index,
             visibility: data.current.visibility,
             pressure: data.current.surface_pressure,
             cloud_cover: data.current.cloud_cover,
+            precipitation: data.current.precipitation,
+            rain: data.current.rain,
+            showers: data.current.showers,
+            snowfall: data.current.snowfall,
+            cape: data.current.cape.unwrap_or(0.0),
+            lightning_potential: data.current.lightning_potential,
         },
         hourly,
         forecast,
-    })
+        elevation: Some(data.elevation),
+    }
+}
+
+/// Checks that coordinates are within valid latitude/longitude ranges.
+pub fn validate_coordinates(lat: f64, lon: f64) -> Result<(), &'static str> {
+    if !(-90.0..=90.0).contains(&lat) {
+        return Err("Latitude must be between -90 and 90");
+    }
+    if !(-180.0..=180.0).contains(&lon) {
+        return Err("Longitude must be between -180 and 180");
+    }
+    Ok(())
 }
 
 /// Checks if coordinates fall within US territory (continental US, Alaska, Hawaii).
 /// Excludes Canadian territory by respecting the US-Canada border.
 fn is_us_bounds(lat: f64, lon: f64) -> bool {
-    // Alaska: lat 51-72, lon -180 to -129
-    let alaska = (51.0..=72.0).contains(&lat) && (-180.0..=-129.0).contains(&lon);
+    // Alaska: lat 51-72 west of the 141st meridian, plus the panhandle
+    // (lat 54.5-60.5, lon -141 to -129) which stops short of Yukon
+    let alaska = ((51.0..=72.0).contains(&lat) && (-180.0..=-141.0).contains(&lon))
+        || ((54.5..=60.5).contains(&lat) && (-141.0..=-129.0).contains(&lon));
     // Hawaii: lat 18-23, lon -161 to -154
     let hawaii = (18.0..=23.0).contains(&lat) && (-161.0..=-154.0).contains(&lon);
 
@@ -381,8 +1133,8 @@ fn is_us_bounds(lat: f64, lon: f64) -> bool {
     // - Great Lakes region: follows the lakes (42-47N)
     // - East (St. Lawrence to Atlantic): ~45N
     let continental = if lon < -95.0 {
-        // Western US: border at 49N
-        (24.0..=49.0).contains(&lat) && (-125.0..=-95.0).contains(&lon)
+        // Western US: border at 49N, Mexico border west of El Paso
+        (mexico_border_lat(lon)..=49.0).contains(&lat) && (-125.0..=-95.0).contains(&lon)
     } else if lon < -84.0 {
         // Upper Midwest (MN, WI, MI upper): border near 49N for MN,
         // drops to ~46N for Lake Superior region
@@ -403,16 +1155,68 @@ fn is_us_bounds(lat: f64, lon: f64) -> bool {
     continental || alaska || hawaii
 }
 
+/// Approximate southern US latitude at a longitude west of the Rio Grande.
+fn mexico_border_lat(lon: f64) -> f64 {
+    if lon < -114.7 {
+        // California: just north of Tijuana
+        32.53
+    } else if lon < -111.07 {
+        // Arizona: diagonal from Yuma down to Nogales
+        32.5 - (lon + 114.7) / 3.63 * 1.17
+    } else if lon < -108.21 {
+        31.33
+    } else if lon < -106.53 {
+        // New Mexico bootheel
+        31.78
+    } else {
+        // Texas follows the Rio Grande, too irregular for a box
+        24.0
+    }
+}
+
 /// Checks if coordinates fall within Canada.
 fn is_canada_bounds(lat: f64, lon: f64) -> bool {
     // Canada: lat 41-84, lon -141 to -52
     (41.0..=84.0).contains(&lat) && (-141.0..=-52.0).contains(&lon)
 }
 
+/// Checks if coordinates fall within Australia.
+fn is_australia_bounds(lat: f64, lon: f64) -> bool {
+    // Mainland and Tasmania: lat -44 to -10, lon 112-154
+    (-44.0..=-10.0).contains(&lat) && (112.0..=154.0).contains(&lon)
+}
+
+/// Checks if coordinates fall within India.
+fn is_india_bounds(lat: f64, lon: f64) -> bool {
+    (8.0..=37.0).contains(&lat)
+        && (68.0..=97.5).contains(&lon)
+        && point_in_polygon(lat, lon, INDIA_OUTLINE)
+}
+
+/// Coarse outline of mainland India as "lat,lon" vertices. It follows the borders
+/// with Pakistan, Nepal, Bhutan and Bangladesh closely enough to keep their major
+/// cities out; the IMD fetch still confirms the country before showing warnings.
+const INDIA_OUTLINE: &str = "23.7,68.1 24.3,68.8 24.3,71.0 25.0,70.7 26.6,70.0 27.8,70.4 29.0,72.3 30.4,73.9 31.0,74.5 31.6,74.55 32.5,74.7 33.3,74.0 34.6,73.9 35.1,76.0 35.6,77.8 34.4,79.5 32.5,79.4 31.0,78.8 30.3,80.2 28.9,80.1 28.0,81.5 27.4,83.4 27.0,84.8 26.5,86.5 26.4,88.1 27.0,88.1 28.1,88.8 27.1,88.9 26.8,89.0 26.8,92.1 27.8,91.6 29.3,94.5 29.4,96.1 28.2,97.4 27.2,97.1 25.8,95.4 24.0,94.1 23.0,93.4 21.9,93.0 21.95,92.3 24.9,92.3 25.2,91.0 25.2,89.9 26.0,89.8 26.3,88.9 26.2,88.3 25.3,88.0 24.2,88.7 22.0,89.0 21.5,88.0 20.3,86.8 19.0,84.7 16.5,82.3 15.8,80.3 13.4,80.4 10.3,79.9 8.0,77.6 8.3,76.9 10.0,76.1 12.8,74.7 15.5,73.6 19.0,72.7 21.0,72.5 22.3,68.9";
+
 /// Checks if coordinates fall within Europe.
 fn is_europe_bounds(lat: f64, lon: f64) -> bool {
-    // Rough bounding box: lat 35-71, lon -25 to 40
-    (35.0..=71.0).contains(&lat) && (-25.0..=40.0).contains(&lon)
+    // Rough bounding box: lat 35-71, lon -25 to 40 in the north.
+    // South of 50N the eastern edge stops at 32E to exclude Kazakhstan,
+    // the Caucasus and the Middle East.
+    let max_lon = if lat >= 50.0 { 40.0 } else { 32.0 };
+    (35.0..=71.0).contains(&lat) && (-25.0..=max_lon).contains(&lon)
+}
+
+/// Checks if coordinates fall within European Russia (not covered by MeteoAlarm).
+fn is_russia_bounds(lat: f64, lon: f64) -> bool {
+    // Central Russia east of Belarus and Ukraine
+    let central = (50.0..=71.0).contains(&lat) && lon >= 32.0;
+    // St. Petersburg region, east of Estonia and south of Finland
+    let northwest = (56.0..=60.5).contains(&lat) && lon >= 28.5;
+    // Kaliningrad exclave between Poland and Lithuania
+    let kaliningrad = (54.3..=55.3).contains(&lat) && (19.6..=22.9).contains(&lon);
+
+    central || northwest || kaliningrad
 }
 
 /// Detects geographic region from coordinates for alert provider selection.
@@ -423,34 +1227,88 @@ pub fn detect_region(lat: f64, lon: f64) -> Region {
     if is_canada_bounds(lat, lon) {
         return Region::Canada;
     }
-    if is_europe_bounds(lat, lon) {
+    if is_australia_bounds(lat, lon) {
+        return Region::Australia;
+    }
+    // India is the only Asian country with an alert provider so far
+    if is_india_bounds(lat, lon) {
+        return Region::Asia;
+    }
+    if is_europe_bounds(lat, lon) && !is_russia_bounds(lat, lon) {
         return Region::Europe;
     }
     Region::Unknown
 }
 
-/// Fetches air quality data from Open-Meteo Air Quality API
+/// Fetches air quality data from Open-Meteo Air Quality API.
+/// With an ETag from the previous fetch, unchanged data comes back as `NotModified`.
 pub async fn fetch_air_quality(
+    api: &OpenMeteoApi,
     latitude: f64,
     longitude: f64,
-) -> Result<AirQualityData, Box<dyn std::error::Error + Send + Sync>> {
+    etag: Option<&str>,
+) -> Result<Conditional<AirQualityData>, WeatherError> {
     let url = format!(
-        "https://air-quality-api.open-meteo.com/v1/air-quality?latitude={}&longitude={}&current=us_aqi,european_aqi,pm2_5,pm10,ozone,nitrogen_dioxide,carbon_monoxide&timezone=auto",
-        latitude, longitude
+        "{}/v1/air-quality?latitude={}&longitude={}&current=us_aqi,european_aqi,pm2_5,pm10,ozone,nitrogen_dioxide,carbon_monoxide&hourly=us_aqi,european_aqi,pm2_5,ozone,nitrogen_dioxide&forecast_hours=24&timezone=auto",
+        api.base_url, latitude, longitude
     );
+    tracing::debug!("GET {}", url);
 
-    let response = http_client().get(&url).send().await?;
-    let data: AirQualityResponse = response.json().await?;
+    let (url, api_key) = (&url, api.api_key.as_deref());
+    let response: Conditional<AirQualityResponse> = retry_with_backoff(
+        || async move {
+            let response = with_etag(open_meteo_get(url, api_key), etag).send().await?;
+            read_json_conditional("Open-Meteo Air Quality", response).await
+        },
+        max_retry_attempts(),
+        RETRY_BASE_DELAY_MS,
+    )
+    .await?;
+    let (data, etag) = match response {
+        Conditional::Modified(data, etag) => (data, etag),
+        Conditional::NotModified => return Ok(Conditional::NotModified),
+    };
 
-    let (aqi, standard) = match detect_region(latitude, longitude) {
+    let (aqi, standard, hourly_values) = match detect_region(latitude, longitude) {
         Region::Europe => (
             data.current.european_aqi.unwrap_or(0),
             AqiStandard::European,
+            data.hourly.european_aqi,
+        ),
+        Region::Canada => {
+            let current = &data.current;
+            let aqhi = calculate_aqhi(
+                current.pm2_5.unwrap_or(0.0),
+                current.ozone.unwrap_or(0.0),
+                current.nitrogen_dioxide.unwrap_or(0.0),
+            );
+            let hourly = &data.hourly;
+            let hourly_aqhi = hourly
+                .pm2_5
+                .iter()
+                .zip(&hourly.ozone)
+                .zip(&hourly.nitrogen_dioxide)
+                .map(|((pm2_5, o3), no2)| Some(calculate_aqhi((*pm2_5)?, (*o3)?, (*no2)?)))
+                .collect();
+            (aqhi, AqiStandard::Canada, hourly_aqhi)
+        }
+        _ => (
+            data.current.us_aqi.unwrap_or(0),
+            AqiStandard::Us,
+            data.hourly.us_aqi,
         ),
-        _ => (data.current.us_aqi.unwrap_or(0), AqiStandard::Us),
     };
 
-    Ok(AirQualityData {
+    // Skip hours the API has no value for
+    let hourly_aqi: Vec<(String, i32)> = data
+        .hourly
+        .time
+        .into_iter()
+        .zip(hourly_values)
+        .filter_map(|(time, aqi)| aqi.map(|a| (time, a)))
+        .collect();
+
+    let air_quality = AirQualityData {
         aqi,
         standard,
         pm2_5: data.current.pm2_5.unwrap_or(0.0),
@@ -458,39 +1316,237 @@ pub async fn fetch_air_quality(
         ozone: data.current.ozone.unwrap_or(0.0),
         nitrogen_dioxide: data.current.nitrogen_dioxide.unwrap_or(0.0),
         carbon_monoxide: data.current.carbon_monoxide.unwrap_or(0.0),
-    })
-}
-
-/// Open-Meteo Air Quality API response
-#[derive(Debug, Deserialize)]
-struct AirQualityResponse {
-    current: AirQualityCurrentData,
+        hourly_aqi,
+    };
+    Ok(Conditional::Modified(air_quality, etag))
 }
 
-#[derive(Debug, Deserialize)]
-struct AirQualityCurrentData {
-    us_aqi: Option<i32>,
-    european_aqi: Option<i32>,
-    pm2_5: Option<f32>,
-    pm10: Option<f32>,
-    ozone: Option<f32>,
-    nitrogen_dioxide: Option<f32>,
-    carbon_monoxide: Option<f32>,
+/// Computes the Canadian AQHI from PM2.5, ozone and NO2 concentrations in µg/m³.
+/// Health Canada's formula expects ozone and NO2 in ppb, so those are converted first.
+pub fn calculate_aqhi(pm2_5: f32, ozone: f32, nitrogen_dioxide: f32) -> i32 {
+    let o3_ppb = f64::from(ozone) / 1.96;
+    let no2_ppb = f64::from(nitrogen_dioxide) / 1.88;
+    let pm2_5 = f64::from(pm2_5);
+
+    let aqhi = (1000.0 / 10.4)
+        * (((0.000871 * no2_ppb).exp() - 1.0)
+            + ((0.000537 * o3_ppb).exp() - 1.0)
+            + ((0.000487 * pm2_5).exp() - 1.0));
+    // The index starts at 1
+    (aqhi.round() as i32).max(1)
 }
 
-/// IP-API.com response structure for geolocation
-#[derive(Debug, Deserialize)]
-struct IpApiResponse {
-    status: String,
-    lat: Option<f64>,
-    lon: Option<f64>,
-    city: Option<String>,
-    #[serde(rename = "regionName")]
-    region_name: Option<String>,
-    country: Option<String>,
+/// Finds the hour with the highest AQI. Earliest hour wins on ties.
+pub fn find_peak_aqi_hour(hourly: &[(String, i32)]) -> Option<(String, i32)> {
+    hourly
+        .iter()
+        .fold(None, |peak: Option<&(String, i32)>, entry| match peak {
+            Some(p) if p.1 >= entry.1 => Some(p),
+            _ => Some(entry),
+        })
+        .cloned()
 }
 
-/// Open-Meteo Geocoding API response structure
+/// Fetches today's hourly solar irradiance from Open-Meteo
+pub async fn fetch_solar_forecast(
+    api: &OpenMeteoApi,
+    latitude: f64,
+    longitude: f64,
+) -> Result<SolarForecastData, WeatherError> {
+    let url = format!(
+        "{}/v1/forecast?latitude={}&longitude={}&hourly=shortwave_radiation&timezone=auto&forecast_days=1",
+        api.base_url, latitude, longitude
+    );
+    tracing::debug!("GET {}", url);
+
+    let response = open_meteo_get(&url, api.api_key.as_deref()).send().await?;
+    let data: SolarResponse = read_json("Open-Meteo", response).await?;
+
+    let hourly: Vec<(String, f32)> = data
+        .hourly
+        .time
+        .into_iter()
+        .zip(data.hourly.shortwave_radiation)
+        .map(|(time, radiation)| (time, radiation.unwrap_or(0.0)))
+        .collect();
+
+    // Each hourly value is the mean W/m² over the hour, so the sum is Wh/m²
+    let total_kwh_today = hourly.iter().map(|(_, w)| w).sum::<f32>() / 1000.0;
+
+    Ok(SolarForecastData {
+        hourly,
+        total_kwh_today,
+    })
+}
+
+/// Fetches current pollen concentrations from the Open-Meteo Air Quality API
+pub async fn fetch_pollen(
+    api: &OpenMeteoApi,
+    latitude: f64,
+    longitude: f64,
+) -> Result<PollenData, WeatherError> {
+    let url = format!(
+        "{}/v1/air-quality?latitude={}&longitude={}&current=alder_pollen,birch_pollen,grass_pollen,mugwort_pollen,olive_pollen,ragweed_pollen&timezone=auto",
+        api.base_url, latitude, longitude
+    );
+    tracing::debug!("GET {}", url);
+
+    let (url, api_key) = (&url, api.api_key.as_deref());
+    let data: PollenResponse = retry_with_backoff(
+        || async move {
+            let response = open_meteo_get(url, api_key).send().await?;
+            read_json("Open-Meteo Pollen", response).await
+        },
+        max_retry_attempts(),
+        RETRY_BASE_DELAY_MS,
+    )
+    .await?;
+
+    // Outside Europe and out of season the API returns nulls; keep them distinct from 0
+    let current = data.current;
+    Ok(PollenData {
+        alder: current.alder_pollen,
+        birch: current.birch_pollen,
+        grass: current.grass_pollen,
+        mugwort: current.mugwort_pollen,
+        olive: current.olive_pollen,
+        ragweed: current.ragweed_pollen,
+    })
+}
+
+/// Fetches current wave conditions from the Open-Meteo Marine API
+pub async fn fetch_marine(
+    api: &OpenMeteoApi,
+    latitude: f64,
+    longitude: f64,
+) -> Result<MarineData, WeatherError> {
+    let url = format!(
+        "{}/v1/marine?latitude={}&longitude={}&current=wave_height,wave_direction,wave_period,swell_wave_height,swell_wave_direction,wind_wave_height&timezone=auto",
+        api.base_url, latitude, longitude
+    );
+    tracing::debug!("GET {}", url);
+
+    let (url, api_key) = (&url, api.api_key.as_deref());
+    let data: MarineResponse = retry_with_backoff(
+        || async move {
+            let response = open_meteo_get(url, api_key).send().await?;
+            read_json("Open-Meteo Marine", response).await
+        },
+        max_retry_attempts(),
+        RETRY_BASE_DELAY_MS,
+    )
+    .await?;
+
+    // Inland locations come back with no wave height at all
+    let current = data.current;
+    let wave_height = current
+        .wave_height
+        .ok_or_else(|| WeatherError::NotFound("No marine data for this location".to_string()))?;
+
+    Ok(MarineData {
+        wave_height,
+        wave_direction: current.wave_direction.unwrap_or(0),
+        wave_period: current.wave_period.unwrap_or(0.0),
+        swell_wave_height: current.swell_wave_height.unwrap_or(0.0),
+        swell_wave_direction: current.swell_wave_direction.unwrap_or(0),
+        wind_wave_height: current.wind_wave_height.unwrap_or(0.0),
+    })
+}
+
+/// Open-Meteo marine response
+#[derive(Debug, Deserialize)]
+struct MarineResponse {
+    current: MarineCurrentData,
+}
+
+#[derive(Debug, Deserialize)]
+struct MarineCurrentData {
+    wave_height: Option<f32>,
+    wave_direction: Option<i32>,
+    wave_period: Option<f32>,
+    swell_wave_height: Option<f32>,
+    swell_wave_direction: Option<i32>,
+    wind_wave_height: Option<f32>,
+}
+
+/// Open-Meteo pollen response
+#[derive(Debug, Deserialize)]
+struct PollenResponse {
+    current: PollenCurrentData,
+}
+
+#[derive(Debug, Deserialize)]
+struct PollenCurrentData {
+    alder_pollen: Option<f32>,
+    birch_pollen: Option<f32>,
+    grass_pollen: Option<f32>,
+    mugwort_pollen: Option<f32>,
+    olive_pollen: Option<f32>,
+    ragweed_pollen: Option<f32>,
+}
+
+/// Estimates daily generation in kWh for a system of the given rated size.
+/// Panels are rated at 1 kW/m² irradiance, so insolation in kWh/m² maps directly to peak sun hours.
+pub fn estimate_solar_generation(total_kwh_per_m2: f32, panel_kw: f32) -> f32 {
+    total_kwh_per_m2 * panel_kw * SOLAR_PERFORMANCE_RATIO
+}
+
+/// Open-Meteo solar radiation response
+#[derive(Debug, Deserialize)]
+struct SolarResponse {
+    hourly: SolarHourlyData,
+}
+
+#[derive(Debug, Deserialize)]
+struct SolarHourlyData {
+    time: Vec<String>,
+    shortwave_radiation: Vec<Option<f32>>,
+}
+
+/// Open-Meteo Air Quality API response
+#[derive(Debug, Deserialize)]
+struct AirQualityResponse {
+    current: AirQualityCurrentData,
+    hourly: AirQualityHourlyData,
+}
+
+#[derive(Debug, Deserialize)]
+struct AirQualityHourlyData {
+    time: Vec<String>,
+    us_aqi: Vec<Option<i32>>,
+    european_aqi: Vec<Option<i32>>,
+    #[serde(default)]
+    pm2_5: Vec<Option<f32>>,
+    #[serde(default)]
+    ozone: Vec<Option<f32>>,
+    #[serde(default)]
+    nitrogen_dioxide: Vec<Option<f32>>,
+}
+
+#[derive(Debug, Deserialize)]
+struct AirQualityCurrentData {
+    us_aqi: Option<i32>,
+    european_aqi: Option<i32>,
+    pm2_5: Option<f32>,
+    pm10: Option<f32>,
+    ozone: Option<f32>,
+    nitrogen_dioxide: Option<f32>,
+    carbon_monoxide: Option<f32>,
+}
+
+/// IP-API.com response structure for geolocation
+#[derive(Debug, Deserialize)]
+struct IpApiResponse {
+    status: String,
+    lat: Option<f64>,
+    lon: Option<f64>,
+    city: Option<String>,
+    #[serde(rename = "regionName")]
+    region_name: Option<String>,
+    country: Option<String>,
+}
+
+/// Open-Meteo Geocoding API response structure
 #[derive(Debug, Deserialize)]
 struct GeocodingResponse {
     results: Option<Vec<GeocodingResult>>,
@@ -533,16 +1589,14 @@ impl LocationResult {
 }
 
 /// Searches for a location by city name using Open-Meteo Geocoding API
-pub async fn search_city(
-    city_name: &str,
-) -> Result<Vec<LocationResult>, Box<dyn std::error::Error>> {
+pub async fn search_city(city_name: &str) -> Result<Vec<LocationResult>, WeatherError> {
     let url = format!(
         "https://geocoding-api.open-meteo.com/v1/search?name={}&count=10&language=en&format=json",
         urlencoding::encode(city_name)
     );
 
     let response = http_client().get(&url).send().await?;
-    let data: GeocodingResponse = response.json().await?;
+    let data: GeocodingResponse = read_json("Open-Meteo Geocoding", response).await?;
 
     if let Some(results) = data.results {
         if !results.is_empty() {
@@ -556,16 +1610,19 @@ pub async fn search_city(
         }
     }
 
-    Err(format!("No results found for '{}'", city_name).into())
+    Err(WeatherError::NotFound(format!(
+        "No results found for '{}'",
+        city_name
+    )))
 }
 
 /// Detects user location automatically using IP-based geolocation.
 /// Returns (latitude, longitude, display_name, country).
-pub async fn detect_location() -> Result<(f64, f64, String, String), Box<dyn std::error::Error>> {
+pub async fn detect_location() -> Result<(f64, f64, String, String), WeatherError> {
     let url = "http://ip-api.com/json/?fields=status,lat,lon,city,regionName,country";
 
     let response = http_client().get(url).send().await?;
-    let data: IpApiResponse = response.json().await?;
+    let data: IpApiResponse = read_json("ip-api", response).await?;
 
     if data.status == "success" {
         if let (Some(lat), Some(lon)) = (data.lat, data.lon) {
@@ -588,79 +1645,106 @@ pub async fn detect_location() -> Result<(f64, f64, String, String), Box<dyn std
     Err("Failed to detect location from IP address".into())
 }
 
-/// Returns true if the country uses imperial units (Fahrenheit, mph, miles).
+/// Returns true if the country (name or ISO code) uses imperial units (Fahrenheit, mph, miles).
 /// Only US, Liberia, and Myanmar officially use imperial.
 pub fn uses_imperial_units(country: &str) -> bool {
-    matches!(country, "United States" | "Liberia" | "Myanmar")
+    matches!(
+        country,
+        "United States" | "Liberia" | "Myanmar" | "US" | "LR" | "MM"
+    )
 }
 
-/// Maps country name to (MeteoAlarm feed slug, ISO country code).
+/// Maps country name or ISO code to (MeteoAlarm feed slug, ISO country code).
 /// Returns None if country is not covered by MeteoAlarm.
 fn get_meteoalarm_info(country: &str) -> Option<(&'static str, &'static str)> {
     match country.to_lowercase().as_str() {
-        "austria" => Some(("austria", "AT")),
-        "belgium" => Some(("belgium", "BE")),
-        "bosnia and herzegovina" => Some(("bosnia-herzegovina", "BA")),
-        "bulgaria" => Some(("bulgaria", "BG")),
-        "croatia" => Some(("croatia", "HR")),
-        "cyprus" => Some(("cyprus", "CY")),
-        "czechia" | "czech republic" => Some(("czechia", "CZ")),
-        "denmark" => Some(("denmark", "DK")),
-        "estonia" => Some(("estonia", "EE")),
-        "finland" => Some(("finland", "FI")),
-        "france" => Some(("france", "FR")),
-        "germany" => Some(("germany", "DE")),
-        "greece" => Some(("greece", "GR")),
-        "hungary" => Some(("hungary", "HU")),
-        "iceland" => Some(("iceland", "IS")),
-        "ireland" => Some(("ireland", "IE")),
-        "israel" => Some(("israel", "IL")),
-        "italy" => Some(("italy", "IT")),
-        "latvia" => Some(("latvia", "LV")),
-        "lithuania" => Some(("lithuania", "LT")),
-        "luxembourg" => Some(("luxembourg", "LU")),
-        "malta" => Some(("malta", "MT")),
-        "moldova" => Some(("moldova", "MD")),
-        "montenegro" => Some(("montenegro", "ME")),
-        "netherlands" => Some(("netherlands", "NL")),
-        "north macedonia" | "macedonia" => Some(("north-macedonia", "MK")),
-        "norway" => Some(("norway", "NO")),
-        "poland" => Some(("poland", "PL")),
-        "portugal" => Some(("portugal", "PT")),
-        "romania" => Some(("romania", "RO")),
-        "serbia" => Some(("serbia", "RS")),
-        "slovakia" => Some(("slovakia", "SK")),
-        "slovenia" => Some(("slovenia", "SI")),
-        "spain" => Some(("spain", "ES")),
-        "sweden" => Some(("sweden", "SE")),
-        "switzerland" => Some(("switzerland", "CH")),
-        "united kingdom" | "uk" => Some(("united-kingdom", "UK")),
+        "austria" | "at" => Some(("austria", "AT")),
+        "belgium" | "be" => Some(("belgium", "BE")),
+        "bosnia and herzegovina" | "ba" => Some(("bosnia-herzegovina", "BA")),
+        "bulgaria" | "bg" => Some(("bulgaria", "BG")),
+        "croatia" | "hr" => Some(("croatia", "HR")),
+        "cyprus" | "cy" => Some(("cyprus", "CY")),
+        "czechia" | "czech republic" | "cz" => Some(("czechia", "CZ")),
+        "denmark" | "dk" => Some(("denmark", "DK")),
+        "estonia" | "ee" => Some(("estonia", "EE")),
+        "finland" | "fi" => Some(("finland", "FI")),
+        "france" | "fr" => Some(("france", "FR")),
+        "germany" | "de" => Some(("germany", "DE")),
+        "greece" | "gr" => Some(("greece", "GR")),
+        "hungary" | "hu" => Some(("hungary", "HU")),
+        "iceland" | "is" => Some(("iceland", "IS")),
+        "ireland" | "ie" => Some(("ireland", "IE")),
+        "israel" | "il" => Some(("israel", "IL")),
+        "italy" | "it" => Some(("italy", "IT")),
+        "latvia" | "lv" => Some(("latvia", "LV")),
+        "lithuania" | "lt" => Some(("lithuania", "LT")),
+        "luxembourg" | "lu" => Some(("luxembourg", "LU")),
+        "malta" | "mt" => Some(("malta", "MT")),
+        "moldova" | "md" => Some(("moldova", "MD")),
+        "montenegro" | "me" => Some(("montenegro", "ME")),
+        "netherlands" | "nl" => Some(("netherlands", "NL")),
+        "north macedonia" | "macedonia" | "mk" => Some(("north-macedonia", "MK")),
+        "norway" | "no" => Some(("norway", "NO")),
+        "poland" | "pl" => Some(("poland", "PL")),
+        "portugal" | "pt" => Some(("portugal", "PT")),
+        "romania" | "ro" => Some(("romania", "RO")),
+        "serbia" | "rs" => Some(("serbia", "RS")),
+        "slovakia" | "sk" => Some(("slovakia", "SK")),
+        "slovenia" | "si" => Some(("slovenia", "SI")),
+        "spain" | "es" => Some(("spain", "ES")),
+        "sweden" | "se" => Some(("sweden", "SE")),
+        "switzerland" | "ch" => Some(("switzerland", "CH")),
+        "united kingdom" | "uk" | "gb" => Some(("united-kingdom", "UK")),
         _ => None,
     }
 }
 
-/// Detects country from coordinates using reverse geocoding.
-async fn detect_country_from_coords(
-    latitude: f64,
-    longitude: f64,
-) -> Result<String, Box<dyn std::error::Error + Send + Sync>> {
-    // Use Open-Meteo geocoding API for reverse lookup
+/// Looks up a display name ("City, State, Country") for coordinates via Nominatim.
+pub async fn reverse_geocode_city(latitude: f64, longitude: f64) -> Result<String, WeatherError> {
     let url = format!(
-        "https://geocoding-api.open-meteo.com/v1/search?name=&latitude={}&longitude={}&count=1",
+        "https://nominatim.openstreetmap.org/reverse?lat={}&lon={}&format=json",
         latitude, longitude
     );
+    tracing::debug!("GET {}", url);
 
-    let response = http_client().get(&url).send().await;
-    if let Ok(resp) = response {
-        if let Ok(data) = resp.json::<GeocodingResponse>().await {
-            if let Some(results) = data.results {
-                if let Some(first) = results.first() {
-                    if let Some(country) = &first.country {
-                        return Ok(country.clone());
-                    }
-                }
-            }
-        }
+    let response = http_client().get(&url).send().await?;
+    let nominatim: NominatimResponse = read_json("Nominatim", response).await?;
+    let address = nominatim
+        .address
+        .ok_or_else(|| WeatherError::NotFound("No address found for coordinates".to_string()))?;
+
+    let place = address
+        .city
+        .or(address.town)
+        .or(address.village)
+        .or(address.municipality)
+        .or(address.county);
+
+    let parts: Vec<String> = [place, address.state, address.country]
+        .into_iter()
+        .flatten()
+        .collect();
+
+    if parts.is_empty() {
+        return Err(WeatherError::NotFound(
+            "No place name found for coordinates".to_string(),
+        ));
+    }
+    Ok(parts.join(", "))
+}
+
+/// Detects country from coordinates using reverse geocoding.
+/// Returns the ISO 3166-1 code ("DE"), or an approximate country name if the lookup fails.
+pub async fn detect_country_from_coords(
+    latitude: f64,
+    longitude: f64,
+) -> Result<String, WeatherError> {
+    // Nominatim resolves border regions precisely, unlike the bounding boxes below
+    if let Some(code) = reverse_geocode(latitude, longitude)
+        .await
+        .and_then(|address| address.country_code)
+    {
+        return Ok(code.to_uppercase());
     }
 
     // Fallback: use approximate country from European bounding boxes
@@ -706,14 +1790,12 @@ fn approximate_european_country(lat: f64, lon: f64) -> &'static str {
 }
 
 /// Fetches active weather alerts from the NWS API for US locations.
-async fn fetch_nws_alerts(
-    latitude: f64,
-    longitude: f64,
-) -> Result<Vec<Alert>, Box<dyn std::error::Error + Send + Sync>> {
+async fn fetch_nws_alerts(latitude: f64, longitude: f64) -> Result<Vec<Alert>, WeatherError> {
     let url = format!(
         "https://api.weather.gov/alerts/active?point={},{}",
         latitude, longitude
     );
+    tracing::debug!("GET {}", url);
 
     let response = http_client()
         .get(&url)
@@ -721,11 +1803,7 @@ async fn fetch_nws_alerts(
         .send()
         .await?;
 
-    if !response.status().is_success() {
-        return Err(format!("NWS API returned status: {}", response.status()).into());
-    }
-
-    let data: NwsAlertsResponse = response.json().await?;
+    let data: NwsAlertsResponse = read_json("NWS API", response).await?;
 
     let alerts: Vec<Alert> = data
         .features
@@ -737,6 +1815,13 @@ async fn fetch_nws_alerts(
                 .ok()?
                 .with_timezone(&Utc);
 
+            let onset = props
+                .onset
+                .as_ref()
+                .or(props.effective.as_ref())
+                .and_then(|o| DateTime::parse_from_rfc3339(o).ok())
+                .map(|dt| dt.with_timezone(&Utc));
+
             let expires = props
                 .expires
                 .as_ref()
@@ -762,6 +1847,8 @@ async fn fetch_nws_alerts(
                 instruction: props.instruction,
                 area_desc: props.area_desc,
                 sent,
+                onset,
+                certainty: props.certainty,
                 expires,
             })
         })
@@ -849,7 +1936,7 @@ async fn fetch_meteoalarm_alerts(
     latitude: f64,
     longitude: f64,
     country: &str,
-) -> Result<Vec<Alert>, Box<dyn std::error::Error + Send + Sync>> {
+) -> Result<Vec<Alert>, WeatherError> {
     let (slug, country_code) = match get_meteoalarm_info(country) {
         Some(info) => info,
         None => {
@@ -865,10 +1952,11 @@ async fn fetch_meteoalarm_alerts(
         "https://feeds.meteoalarm.org/feeds/meteoalarm-legacy-atom-{}",
         slug
     );
+    tracing::debug!("GET {}", url);
 
     let response = http_client().get(&url).send().await?;
     if !response.status().is_success() {
-        return Err(format!("MeteoAlarm returned status: {}", response.status()).into());
+        return Err(WeatherError::from_status("MeteoAlarm", response.status()));
     }
 
     let xml_text = response.text().await?;
@@ -915,6 +2003,14 @@ fn parse_meteoalarm_entry(entry: MeteoAlarmEntry, user_emma_id: &Option<String>)
         .map(|dt| dt.with_timezone(&Utc))
         .unwrap_or(now);
 
+    // Parse onset timestamp
+    let onset = entry
+        .cap_onset
+        .as_ref()
+        .or(entry.cap_effective.as_ref())
+        .and_then(|s| DateTime::parse_from_rfc3339(s).ok())
+        .map(|dt| dt.with_timezone(&Utc));
+
     // Parse expires timestamp
     let expires = entry
         .cap_expires
@@ -950,6 +2046,8 @@ fn parse_meteoalarm_entry(entry: MeteoAlarmEntry, user_emma_id: &Option<String>)
         instruction: None,
         area_desc: entry.cap_area_desc.unwrap_or_default(),
         sent,
+        onset,
+        certainty: entry.cap_certainty,
         expires,
     })
 }
@@ -1046,11 +2144,35 @@ fn point_in_polygon(lat: f64, lon: f64, polygon_str: &str) -> bool {
     inside
 }
 
+/// Fetches an ECCC directory listing, reusing a cached copy if it is still fresh.
+async fn fetch_eccc_listing(url: &str) -> Option<String> {
+    if let Ok(cache) = eccc_listing_cache().lock() {
+        if let Some((html, fetched_at)) = cache.get(url) {
+            if fetched_at.elapsed() < ECCC_LISTING_TTL {
+                tracing::debug!("Using cached listing for {}", url);
+                return Some(html.clone());
+            }
+        }
+    }
+
+    tracing::debug!("GET {}", url);
+    let response = match http_client().get(url).send().await {
+        Ok(resp) if resp.status().is_success() => resp,
+        _ => return None,
+    };
+    let html = response.text().await.ok()?;
+
+    if let Ok(mut cache) = eccc_listing_cache().lock() {
+        // Drop stale entries so listings from previous days don't accumulate
+        cache.retain(|_, (_, fetched_at)| fetched_at.elapsed() < ECCC_LISTING_TTL);
+        cache.insert(url.to_string(), (html.clone(), Instant::now()));
+    }
+
+    Some(html)
+}
+
 /// Fetches active weather alerts from ECCC (Environment and Climate Change Canada).
-async fn fetch_eccc_alerts(
-    latitude: f64,
-    longitude: f64,
-) -> Result<Vec<Alert>, Box<dyn std::error::Error + Send + Sync>> {
+async fn fetch_eccc_alerts(latitude: f64, longitude: f64) -> Result<Vec<Alert>, WeatherError> {
     let offices = get_eccc_office_codes(latitude, longitude);
     let today = chrono::Utc::now().format("%Y%m%d").to_string();
     let client = http_client();
@@ -1065,14 +2187,9 @@ async fn fetch_eccc_alerts(
             today, office
         );
 
-        let dir_response = match client.get(&dir_url).send().await {
-            Ok(resp) if resp.status().is_success() => resp,
-            _ => continue,
-        };
-
-        let dir_html = match dir_response.text().await {
-            Ok(text) => text,
-            Err(_) => continue,
+        let dir_html = match fetch_eccc_listing(&dir_url).await {
+            Some(html) => html,
+            None => continue,
         };
 
         // Parse hour directories from HTML listing
@@ -1098,14 +2215,9 @@ async fn fetch_eccc_alerts(
         for hour in hour_dirs {
             let hour_url = format!("{}{}/", dir_url, hour);
 
-            let hour_response = match client.get(&hour_url).send().await {
-                Ok(resp) if resp.status().is_success() => resp,
-                _ => continue,
-            };
-
-            let hour_html = match hour_response.text().await {
-                Ok(text) => text,
-                Err(_) => continue,
+            let hour_html = match fetch_eccc_listing(&hour_url).await {
+                Some(html) => html,
+                None => continue,
             };
 
             // Parse CAP file links
@@ -1126,6 +2238,7 @@ async fn fetch_eccc_alerts(
 
             for cap_file in cap_files {
                 let cap_url = format!("{}{}", hour_url, cap_file);
+                tracing::debug!("GET {}", cap_url);
 
                 let cap_response = match client.get(&cap_url).send().await {
                     Ok(resp) if resp.status().is_success() => resp,
@@ -1218,6 +2331,13 @@ fn parse_eccc_cap(
         .map(|dt| dt.with_timezone(&Utc))
         .unwrap_or(now);
 
+    let onset = info
+        .onset
+        .as_ref()
+        .or(info.effective.as_ref())
+        .and_then(|s| s.parse::<DateTime<chrono::FixedOffset>>().ok())
+        .map(|dt| dt.with_timezone(&Utc));
+
     let expires = info
         .expires
         .as_ref()
@@ -1246,16 +2366,258 @@ fn parse_eccc_cap(
         instruction: info.instruction.clone(),
         area_desc,
         sent,
+        onset,
+        certainty: info.certainty.clone(),
         expires,
     })
 }
 
-/// Fetches active weather alerts based on location.
-/// Dispatches to appropriate regional API based on detected region.
-pub async fn fetch_alerts(
-    latitude: f64,
-    longitude: f64,
-) -> Result<Vec<Alert>, Box<dyn std::error::Error + Send + Sync>> {
+/// Returns the BOM warnings RSS feed for an ISO 3166-2 state code ("AU-NSW").
+fn bom_feed_url(state_code: &str) -> Option<&'static str> {
+    let url = match state_code {
+        "AU-NSW" | "AU-ACT" => "http://www.bom.gov.au/fwo/IDZ00054.warnings_nsw.xml",
+        "AU-NT" => "http://www.bom.gov.au/fwo/IDZ00055.warnings_nt.xml",
+        "AU-QLD" => "http://www.bom.gov.au/fwo/IDZ00056.warnings_qld.xml",
+        "AU-SA" => "http://www.bom.gov.au/fwo/IDZ00057.warnings_sa.xml",
+        "AU-TAS" => "http://www.bom.gov.au/fwo/IDZ00058.warnings_tas.xml",
+        "AU-VIC" => "http://www.bom.gov.au/fwo/IDZ00059.warnings_vic.xml",
+        "AU-WA" => "http://www.bom.gov.au/fwo/IDZ00060.warnings_wa.xml",
+        _ => return None,
+    };
+    Some(url)
+}
+
+/// Approximates the Australian state from coordinates when reverse geocoding fails.
+fn approximate_australian_state(lat: f64, lon: f64) -> &'static str {
+    if lat < -39.5 {
+        "AU-TAS"
+    } else if lon < 129.0 {
+        "AU-WA"
+    } else if lon < 138.0 {
+        if lat > -26.0 {
+            "AU-NT"
+        } else {
+            "AU-SA"
+        }
+    } else if lat > -29.0 {
+        "AU-QLD"
+    } else if lat < -36.0 || (lon < 141.0 && lat < -34.0) {
+        "AU-VIC"
+    } else {
+        "AU-NSW"
+    }
+}
+
+/// Looks up the address details for a location via Nominatim.
+async fn reverse_geocode(latitude: f64, longitude: f64) -> Option<NominatimAddress> {
+    let url = format!(
+        "https://nominatim.openstreetmap.org/reverse?lat={}&lon={}&format=json&addressdetails=1&accept-language=en",
+        latitude, longitude
+    );
+
+    let response = http_client().get(&url).send().await.ok()?;
+    let nominatim: NominatimResponse = response.json().await.ok()?;
+    nominatim.address
+}
+
+/// Maps a BOM warning title to a severity, since the RSS feed carries none.
+fn bom_severity(title: &str) -> AlertSeverity {
+    let title = title.to_lowercase();
+    if title.contains("cyclone") || title.contains("extreme") || title.contains("emergency") {
+        AlertSeverity::Extreme
+    } else if title.contains("severe") || title.contains("major") {
+        AlertSeverity::Severe
+    } else if title.contains("watch") || title.contains("minor") || title.contains("advice") {
+        AlertSeverity::Minor
+    } else if title.contains("warning") {
+        AlertSeverity::Moderate
+    } else {
+        AlertSeverity::Unknown
+    }
+}
+
+/// Fetches active warnings from the Bureau of Meteorology for Australian locations.
+/// BOM publishes warnings per state, so all warnings for the user's state are returned.
+async fn fetch_bom_alerts(latitude: f64, longitude: f64) -> Result<Vec<Alert>, WeatherError> {
+    let state_code = reverse_geocode(latitude, longitude)
+        .await
+        .and_then(|address| address.iso_state)
+        .unwrap_or_else(|| approximate_australian_state(latitude, longitude).to_string());
+
+    let Some(url) = bom_feed_url(&state_code) else {
+        tracing::debug!("No BOM warnings feed for '{}'", state_code);
+        return Ok(vec![]);
+    };
+    tracing::debug!("GET {}", url);
+
+    let response = http_client().get(url).send().await?;
+    if !response.status().is_success() {
+        return Err(WeatherError::from_status("BOM", response.status()));
+    }
+
+    let xml_text = response.text().await?;
+    let feed: RssFeed = quick_xml::de::from_str(&xml_text)?;
+    let now = Utc::now();
+
+    let alerts: Vec<Alert> = feed
+        .channel
+        .items
+        .into_iter()
+        .filter_map(|item| {
+            let title = item.title?;
+            // Cancellations remain in the feed until the next update
+            if title.to_lowercase().starts_with("cancellation") {
+                return None;
+            }
+
+            let sent = item
+                .pub_date
+                .as_deref()
+                .and_then(|s| DateTime::parse_from_rfc2822(s).ok())
+                .map(|dt| dt.with_timezone(&Utc))
+                .unwrap_or(now);
+            let expires = sent + chrono::Duration::hours(24);
+            if expires < now {
+                return None;
+            }
+
+            // Titles read "Severe Thunderstorm Warning for Sydney Metropolitan"
+            let (event, area) = match title.split_once(" for ") {
+                Some((event, area)) => (event.to_string(), area.to_string()),
+                None => (title.clone(), String::new()),
+            };
+
+            Some(Alert {
+                id: item.guid.or(item.link).unwrap_or_else(|| title.clone()),
+                severity: bom_severity(&title),
+                event,
+                urgency: "Unknown".to_string(),
+                headline: title,
+                description: item.description.unwrap_or_default(),
+                instruction: None,
+                area_desc: area,
+                sent,
+                onset: None,
+                certainty: None,
+                expires,
+            })
+        })
+        .collect();
+
+    tracing::debug!(
+        "Fetched {} alert(s) from BOM ({})",
+        alerts.len(),
+        state_code
+    );
+    Ok(alerts)
+}
+
+/// Maps IMD colour-coded warning levels to a severity.
+fn imd_severity(text: &str) -> AlertSeverity {
+    let text = text.to_lowercase();
+    // Match colours as whole words so "predicted" doesn't read as red
+    let has_word = |word: &str| {
+        text.split(|c: char| !c.is_alphanumeric())
+            .any(|w| w == word)
+    };
+    if has_word("red") || text.contains("take action") {
+        AlertSeverity::Extreme
+    } else if has_word("orange") || text.contains("be prepared") {
+        AlertSeverity::Severe
+    } else if has_word("yellow") || text.contains("be updated") {
+        AlertSeverity::Moderate
+    } else if has_word("green") {
+        AlertSeverity::Minor
+    } else {
+        AlertSeverity::Unknown
+    }
+}
+
+/// Fetches active warnings from the India Meteorological Department.
+/// The feed covers the whole country, so items are filtered by the user's state. Without a
+/// confirmed Indian address there's no way to tell which warnings apply, so none are shown.
+async fn fetch_imd_alerts(latitude: f64, longitude: f64) -> Result<Vec<Alert>, WeatherError> {
+    let Some(address) = reverse_geocode(latitude, longitude).await else {
+        tracing::warn!("Reverse geocoding failed; skipping IMD alerts");
+        return Ok(Vec::new());
+    };
+    if address.country_code.as_deref() != Some("in") {
+        tracing::debug!("Location is outside India; skipping IMD alerts");
+        return Ok(Vec::new());
+    }
+    let state = address.state.map(|state| state.to_lowercase());
+
+    let url = "https://mausam.imd.gov.in/rss";
+    tracing::debug!("GET {}", url);
+
+    let response = http_client().get(url).send().await?;
+    if !response.status().is_success() {
+        return Err(WeatherError::from_status("IMD", response.status()));
+    }
+
+    let xml_text = response.text().await?;
+    let feed: RssFeed = quick_xml::de::from_str(&xml_text)?;
+    let now = Utc::now();
+
+    let alerts: Vec<Alert> = feed
+        .channel
+        .items
+        .into_iter()
+        .filter_map(|item| {
+            let title = item.title?;
+            let description = item.description.unwrap_or_default();
+
+            if let Some(state) = &state {
+                let text = format!("{} {}", title, description).to_lowercase();
+                if !text.contains(state.as_str()) {
+                    return None;
+                }
+            }
+
+            let sent = item
+                .pub_date
+                .as_deref()
+                .and_then(|s| DateTime::parse_from_rfc2822(s).ok())
+                .map(|dt| dt.with_timezone(&Utc))
+                .unwrap_or(now);
+            let expires = sent + chrono::Duration::hours(24);
+            if expires < now {
+                return None;
+            }
+
+            Some(Alert {
+                id: item.guid.or(item.link).unwrap_or_else(|| title.clone()),
+                severity: imd_severity(&format!("{} {}", title, description)),
+                event: title.clone(),
+                urgency: "Unknown".to_string(),
+                headline: title,
+                description,
+                instruction: None,
+                area_desc: String::new(),
+                sent,
+                onset: None,
+                certainty: None,
+                expires,
+            })
+        })
+        .collect();
+
+    tracing::debug!("Fetched {} alert(s) from IMD", alerts.len());
+    Ok(alerts)
+}
+
+/// Fetches active weather alerts based on location, retrying transient failures.
+pub async fn fetch_alerts(latitude: f64, longitude: f64) -> Result<Vec<Alert>, WeatherError> {
+    retry_with_backoff(
+        || fetch_region_alerts(latitude, longitude),
+        max_retry_attempts(),
+        RETRY_BASE_DELAY_MS,
+    )
+    .await
+}
+
+/// Fetches alerts once from the regional API for the detected region.
+async fn fetch_region_alerts(latitude: f64, longitude: f64) -> Result<Vec<Alert>, WeatherError> {
     match detect_region(latitude, longitude) {
         Region::Us => fetch_nws_alerts(latitude, longitude).await,
         Region::Europe => {
@@ -1265,10 +2627,34 @@ pub async fn fetch_alerts(
             fetch_meteoalarm_alerts(latitude, longitude, &country).await
         }
         Region::Canada => fetch_eccc_alerts(latitude, longitude).await,
+        Region::Australia => fetch_bom_alerts(latitude, longitude).await,
+        Region::Asia => fetch_imd_alerts(latitude, longitude).await,
         Region::Unknown => Ok(vec![]),
     }
 }
 
+/// Formats the time remaining until `dt` as "23 min", "4 h 15 min" or "2 days".
+pub fn format_duration_until(dt: DateTime<Utc>) -> String {
+    format_minutes((dt - Utc::now()).num_minutes())
+}
+
+/// Formats the time elapsed since `dt`, in the same style as `format_duration_until`.
+pub fn format_duration_since(dt: DateTime<Utc>) -> String {
+    format_minutes((Utc::now() - dt).num_minutes())
+}
+
+fn format_minutes(minutes: i64) -> String {
+    let minutes = minutes.max(0);
+    let (days, hours, mins) = (minutes / 1440, minutes / 60 % 24, minutes % 60);
+    match (days, minutes / 60) {
+        (0, 0) => format!("{} min", mins),
+        (0, _) if mins == 0 => format!("{} h", hours),
+        (0, _) => format!("{} h {} min", hours, mins),
+        (1, _) => "1 day".to_string(),
+        _ => format!("{} days", days),
+    }
+}
+
 /// Converts WMO weather codes to human-readable descriptions
 pub fn weathercode_to_description(code: i32) -> &'static str {
     match code {
@@ -1289,9 +2675,13 @@ pub fn weathercode_to_description(code: i32) -> &'static str {
     }
 }
 
-/// Formats ISO timestamp to hour (e.g., "2025-01-20T14:00" -> "2:00 PM")
-pub fn format_hour(time_str: &str) -> String {
+/// Formats ISO timestamp to hour (e.g., "2025-01-20T14:00" -> "2:00 PM" or "14:00")
+pub fn format_hour(time_str: &str, time_format: TimeFormat) -> String {
+    let twenty_four_hour = time_format.uses_24_hour();
     if let Ok(datetime) = chrono::DateTime::parse_from_rfc3339(time_str) {
+        if twenty_four_hour {
+            return datetime.format("%H:%M").to_string();
+        }
         datetime
             .format("%I:%M %p")
             .to_string()
@@ -1302,6 +2692,9 @@ pub fn format_hour(time_str: &str) -> String {
         if let Some(time_part) = time_str.split('T').nth(1) {
             if let Some(hour_str) = time_part.split(':').next() {
                 if let Ok(hour) = hour_str.parse::<u32>() {
+                    if twenty_four_hour {
+                        return format!("{:02}:00", hour);
+                    }
                     let (display_hour, period) = if hour == 0 {
                         (12, "AM")
                     } else if hour < 12 {
@@ -1319,9 +2712,13 @@ pub fn format_hour(time_str: &str) -> String {
     }
 }
 
-/// Formats ISO timestamp to time (e.g., "2025-01-20T06:30:00" -> "6:30 AM")
-pub fn format_time(time_str: &str) -> String {
+/// Formats ISO timestamp to time (e.g., "2025-01-20T06:30:00" -> "6:30 AM" or "06:30")
+pub fn format_time(time_str: &str, time_format: TimeFormat) -> String {
+    let twenty_four_hour = time_format.uses_24_hour();
     if let Ok(datetime) = chrono::DateTime::parse_from_rfc3339(time_str) {
+        if twenty_four_hour {
+            return datetime.format("%H:%M").to_string();
+        }
         datetime
             .format("%I:%M %p")
             .to_string()
@@ -1336,6 +2733,9 @@ pub fn format_time(time_str: &str) -> String {
                     time_components[0].parse::<u32>(),
                     time_components[1].parse::<u32>(),
                 ) {
+                    if twenty_four_hour {
+                        return format!("{:02}:{:02}", hour, minute);
+                    }
                     let (display_hour, period) = if hour == 0 {
                         (12, "AM")
                     } else if hour < 12 {
@@ -1353,25 +2753,104 @@ pub fn format_time(time_str: &str) -> String {
     }
 }
 
-/// Determines if current time is night (before sunrise or after sunset).
-/// Falls back to 6pm-6am if parsing fails.
-pub fn is_night_time(sunrise: &str, sunset: &str) -> bool {
-    use chrono::{Local, NaiveDateTime, TimeZone, Timelike};
+/// Formats a date and time (e.g., "Jan 20 2:30 PM" or "Jan 20 14:30") in the given zone
+pub fn format_date_time<Tz: chrono::TimeZone>(
+    datetime: &DateTime<Tz>,
+    time_format: TimeFormat,
+) -> String
+where
+    Tz::Offset: std::fmt::Display,
+{
+    if time_format.uses_24_hour() {
+        datetime.format("%b %d %H:%M").to_string()
+    } else {
+        datetime.format("%b %d %-I:%M %p").to_string()
+    }
+}
 
-    let now = Local::now();
+/// Parses an Open-Meteo local timestamp ("2025-01-20T06:30" with optional seconds).
+pub fn parse_local_timestamp(time_str: &str) -> Option<chrono::NaiveDateTime> {
+    chrono::NaiveDateTime::parse_from_str(time_str, "%Y-%m-%dT%H:%M:%S")
+        .or_else(|_| chrono::NaiveDateTime::parse_from_str(time_str, "%Y-%m-%dT%H:%M"))
+        .ok()
+}
 
-    // Parse sunrise/sunset times (format: "2025-01-20T06:30")
-    let parse_time = |time_str: &str| -> Option<chrono::DateTime<Local>> {
-        // Try parsing with seconds first, then without
-        NaiveDateTime::parse_from_str(time_str, "%Y-%m-%dT%H:%M:%S")
-            .or_else(|_| NaiveDateTime::parse_from_str(time_str, "%Y-%m-%dT%H:%M"))
-            .ok()
-            .and_then(|naive| Local.from_local_datetime(&naive).single())
-    };
+/// Shifts a local timestamp by the given number of minutes.
+/// Returns the input unchanged if it can't be parsed.
+pub fn shift_time(time_str: &str, minutes: i64) -> String {
+    match parse_local_timestamp(time_str) {
+        Some(time) => (time + chrono::Duration::minutes(minutes))
+            .format("%Y-%m-%dT%H:%M")
+            .to_string(),
+        None => time_str.to_string(),
+    }
+}
 
-    match (parse_time(sunrise), parse_time(sunset)) {
-        (Some(sunrise_time), Some(sunset_time)) => {
-            now < sunrise_time || now > sunset_time
+/// Computes solar noon as the midpoint between sunrise and sunset.
+/// Open-Meteo doesn't provide it directly; the midpoint is accurate to within a minute.
+fn solar_noon(sunrise: &str, sunset: &str) -> String {
+    match (
+        parse_local_timestamp(sunrise),
+        parse_local_timestamp(sunset),
+    ) {
+        (Some(rise), Some(set)) => (rise + (set - rise) / 2)
+            .format("%Y-%m-%dT%H:%M")
+            .to_string(),
+        _ => String::new(),
+    }
+}
+
+/// Approximate offset of civil twilight from sunrise/sunset in minutes.
+pub const CIVIL_TWILIGHT_MINUTES: i64 = 30;
+
+/// Returns the moon phase for a date as a fraction of the lunar cycle
+/// (0.0 = new moon, 0.5 = full moon).
+pub fn moon_phase_fraction(date: chrono::NaiveDate) -> f64 {
+    // Reference new moon: 2000-01-06 18:14 UTC
+    const SYNODIC_MONTH_DAYS: f64 = 29.530_588_853;
+    let reference = chrono::NaiveDate::from_ymd_opt(2000, 1, 6)
+        .and_then(|d| d.and_hms_opt(18, 14, 0))
+        .expect("valid reference date");
+    let noon = date.and_hms_opt(12, 0, 0).expect("valid time");
+    let days = (noon - reference).num_seconds() as f64 / 86_400.0;
+    (days / SYNODIC_MONTH_DAYS).rem_euclid(1.0)
+}
+
+/// Converts a moon phase fraction to its localized name
+pub fn moon_phase_name(phase: f64) -> String {
+    match phase {
+        p if p < 0.0339 => crate::fl!("moon-new"),
+        p if p < 0.216 => crate::fl!("moon-waxing-crescent"),
+        p if p < 0.284 => crate::fl!("moon-first-quarter"),
+        p if p < 0.466 => crate::fl!("moon-waxing-gibbous"),
+        p if p < 0.534 => crate::fl!("moon-full"),
+        p if p < 0.716 => crate::fl!("moon-waning-gibbous"),
+        p if p < 0.784 => crate::fl!("moon-last-quarter"),
+        p if p < 0.966 => crate::fl!("moon-waning-crescent"),
+        _ => crate::fl!("moon-new"),
+    }
+}
+
+/// Percentage of the moon's disc that is lit for a moon phase fraction.
+pub fn moon_illumination(phase: f64) -> f64 {
+    (1.0 - (phase * std::f64::consts::TAU).cos()) * 50.0
+}
+
+/// Determines if current time is night (before sunrise or after sunset).
+/// Falls back to 6pm-6am if parsing fails.
+pub fn is_night_time(sunrise: &str, sunset: &str) -> bool {
+    use chrono::{Local, TimeZone, Timelike};
+
+    let now = Local::now();
+
+    // Parse sunrise/sunset times (format: "2025-01-20T06:30")
+    let parse_time = |time_str: &str| -> Option<chrono::DateTime<Local>> {
+        parse_local_timestamp(time_str).and_then(|naive| Local.from_local_datetime(&naive).single())
+    };
+
+    match (parse_time(sunrise), parse_time(sunset)) {
+        (Some(sunrise_time), Some(sunset_time)) => {
+            now < sunrise_time || now > sunset_time
         }
         _ => {
             // Fallback to hardcoded 6am-6pm if parsing fails
@@ -1390,6 +2869,155 @@ pub fn format_date(date_str: &str) -> String {
     }
 }
 
+/// Reduces station pressure to sea-level pressure using the barometric formula.
+pub fn to_sea_level_pressure(station_hpa: f32, elevation_m: f32, temp_k: f32) -> f32 {
+    // Standard atmosphere lapse rate in K/m
+    const LAPSE_RATE: f32 = 0.0065;
+    let ratio = 1.0 - (LAPSE_RATE * elevation_m) / (temp_k + LAPSE_RATE * elevation_m);
+    station_hpa * ratio.powf(-5.257)
+}
+
+/// Localized lightning risk level from the lightning potential index (J/kg).
+/// Outside the area where that's modelled, CAPE (J/kg) gives a rougher estimate.
+pub fn lightning_risk_label(potential: Option<f32>, cape: f32) -> String {
+    match (potential, cape) {
+        (Some(p), _) if p <= 0.0 => crate::fl!("lightning-none"),
+        (Some(p), _) if p < 2.0 => crate::fl!("lightning-low"),
+        (Some(p), _) if p < 5.0 => crate::fl!("lightning-moderate"),
+        (Some(_), _) => crate::fl!("lightning-high"),
+        (None, c) if c < 1000.0 => crate::fl!("lightning-low"),
+        (None, c) if c < 2500.0 => crate::fl!("lightning-moderate"),
+        (None, _) => crate::fl!("lightning-high"),
+    }
+}
+
+/// Fosberg Fire Weather Index (0-100), which combines the equilibrium moisture
+/// content of fine fuels with wind speed.
+pub fn fosberg_index(temp_c: f32, humidity: i32, windspeed_kmh: f32) -> f32 {
+    let temp_f = temp_c * 9.0 / 5.0 + 32.0;
+    let rh = humidity.clamp(0, 100) as f32;
+    let wind_mph = windspeed_kmh.max(0.0) / 1.609_34;
+
+    // Equilibrium moisture content (%) of fine dead fuels
+    let moisture = if rh < 10.0 {
+        0.03229 + 0.281_073 * rh - 0.000_578 * rh * temp_f
+    } else if rh <= 50.0 {
+        2.22749 + 0.160_107 * rh - 0.01478 * temp_f
+    } else {
+        21.0606 + 0.005_565 * rh * rh - 0.000_35 * rh * temp_f - 0.483_199 * rh
+    };
+    let m = (moisture / 30.0).max(0.0);
+    let damping = 1.0 - 2.0 * m + 1.5 * m * m - 0.5 * m * m * m;
+    (damping * (1.0 + wind_mph * wind_mph).sqrt() / 0.3002).clamp(0.0, 100.0)
+}
+
+/// Localized fire weather danger level from the Fosberg Fire Weather Index.
+pub fn fire_weather_index(temp_c: f32, humidity: i32, windspeed_kmh: f32) -> String {
+    match fosberg_index(temp_c, humidity, windspeed_kmh) {
+        i if i < 15.0 => crate::fl!("fire-danger-low"),
+        i if i < 30.0 => crate::fl!("fire-danger-moderate"),
+        i if i < 50.0 => crate::fl!("fire-danger-high"),
+        i if i < 75.0 => crate::fl!("fire-danger-very-high"),
+        _ => crate::fl!("fire-danger-extreme"),
+    }
+}
+
+/// Great-circle distance in km between two coordinates (haversine formula).
+pub fn haversine_distance(lat1: f64, lon1: f64, lat2: f64, lon2: f64) -> f64 {
+    const EARTH_RADIUS_KM: f64 = 6371.0;
+    let d_lat = (lat2 - lat1).to_radians();
+    let d_lon = (lon2 - lon1).to_radians();
+    let a = (d_lat / 2.0).sin().powi(2)
+        + lat1.to_radians().cos() * lat2.to_radians().cos() * (d_lon / 2.0).sin().powi(2);
+    2.0 * EARTH_RADIUS_KM * a.sqrt().asin()
+}
+
+/// Dew point in °C from temperature and relative humidity (Magnus formula).
+/// Humidity is clamped to 1-100% since the formula is undefined at 0%.
+pub fn magnus_dewpoint(temp_c: f32, relative_humidity: i32) -> f32 {
+    const B: f32 = 17.625;
+    const C: f32 = 243.04;
+    let rh = relative_humidity.clamp(1, 100) as f32 / 100.0;
+    let gamma = rh.ln() + B * temp_c / (C + temp_c);
+    C * gamma / (B - gamma)
+}
+
+/// Whether a garden needs watering: the day's evapotranspiration (mm)
+/// outpaces the rain expected to replace it.
+pub fn needs_watering(et0_mm: f32, precipitation_mm: f32) -> bool {
+    et0_mm > precipitation_mm
+}
+
+/// Temperature/dew point spread in °C below which fog is likely.
+const FOG_DEWPOINT_SPREAD_C: f32 = 3.0;
+
+/// Whether the air is close enough to saturation for fog to form.
+pub fn fog_risk(temp_c: f32, dewpoint_c: f32) -> bool {
+    temp_c - dewpoint_c < FOG_DEWPOINT_SPREAD_C
+}
+
+/// NWS heat index (Rothfusz regression) in °F.
+/// Only defined at 80°F and above with at least 40% humidity.
+pub fn heat_index(temp_f: f32, humidity: i32) -> Option<f32> {
+    if temp_f < 80.0 || humidity < 40 {
+        return None;
+    }
+    let t = temp_f;
+    let rh = humidity as f32;
+    Some(
+        -42.379 + 2.049_015_3 * t + 10.143_331 * rh
+            - 0.224_755_4 * t * rh
+            - 0.006_837_83 * t * t
+            - 0.054_817_17 * rh * rh
+            + 0.001_228_74 * t * t * rh
+            + 0.000_852_82 * t * rh * rh
+            - 0.000_001_99 * t * t * rh * rh,
+    )
+}
+
+/// NWS wind chill in °F.
+/// Only defined at 50°F and below with wind of at least 3 mph.
+pub fn wind_chill(temp_f: f32, windspeed_mph: f32) -> Option<f32> {
+    if temp_f > 50.0 || windspeed_mph < 3.0 {
+        return None;
+    }
+    let v = windspeed_mph.powf(0.16);
+    Some(35.74 + 0.6215 * temp_f - 35.75 * v + 0.4275 * temp_f * v)
+}
+
+/// Converts a wind speed in km/h to the Beaufort scale (0-12).
+pub fn to_beaufort(kmh: f32) -> u8 {
+    // Upper bounds in km/h for forces 0 through 11; anything above is force 12
+    const LIMITS: [f32; 12] = [
+        1.0, 6.0, 12.0, 20.0, 29.0, 39.0, 50.0, 62.0, 75.0, 89.0, 103.0, 118.0,
+    ];
+    LIMITS.iter().position(|&limit| kmh < limit).unwrap_or(12) as u8
+}
+
+/// Converts a wind speed in m/s to the Beaufort scale (0-12).
+pub fn ms_to_beaufort(ms: f32) -> u8 {
+    to_beaufort(ms * 3.6)
+}
+
+/// Returns the localized standard name for a Beaufort force.
+pub fn beaufort_to_description(force: u8) -> String {
+    match force {
+        0 => crate::fl!("beaufort-calm"),
+        1 => crate::fl!("beaufort-light-air"),
+        2 => crate::fl!("beaufort-light-breeze"),
+        3 => crate::fl!("beaufort-gentle-breeze"),
+        4 => crate::fl!("beaufort-moderate-breeze"),
+        5 => crate::fl!("beaufort-fresh-breeze"),
+        6 => crate::fl!("beaufort-strong-breeze"),
+        7 => crate::fl!("beaufort-near-gale"),
+        8 => crate::fl!("beaufort-gale"),
+        9 => crate::fl!("beaufort-strong-gale"),
+        10 => crate::fl!("beaufort-storm"),
+        11 => crate::fl!("beaufort-violent-storm"),
+        _ => crate::fl!("beaufort-hurricane"),
+    }
+}
+
 /// Converts wind direction in degrees to compass direction
 pub fn wind_direction_to_compass(degrees: i32) -> &'static str {
     match degrees {
@@ -1405,6 +3033,27 @@ pub fn wind_direction_to_compass(degrees: i32) -> &'static str {
     }
 }
 
+/// Converts wind direction in degrees to an arrow pointing where the wind blows.
+/// Meteorological direction is where the wind comes from, so the arrow points the opposite way.
+pub fn wind_direction_to_arrow(degrees: i32) -> char {
+    compass_to_arrow(wind_direction_to_compass((degrees + 180).rem_euclid(360)))
+}
+
+/// Converts an 8-point compass direction to an arrow pointing that way.
+pub fn compass_to_arrow(direction: &str) -> char {
+    match direction {
+        "N" => '↑',
+        "NE" => '↗',
+        "E" => '→',
+        "SE" => '↘',
+        "S" => '↓',
+        "SW" => '↙',
+        "W" => '←',
+        "NW" => '↖',
+        _ => '•',
+    }
+}
+
 /// Converts WMO weather codes to freedesktop icon names
 /// https://specifications.freedesktop.org/icon-naming-spec/latest/
 pub fn weathercode_to_icon_name(code: i32, is_night: bool) -> &'static str {
@@ -1458,42 +3107,1068 @@ pub fn weathercode_to_icon_name(code: i32, is_night: bool) -> &'static str {
     }
 }
 
-/// Converts US AQI value to description
-pub fn us_aqi_to_description(aqi: i32) -> &'static str {
+/// Converts a UV index value to its localized WHO exposure category
+pub fn uv_index_to_category(uv: f32) -> String {
+    match uv {
+        u if u < 3.0 => crate::fl!("uv-low"),
+        u if u < 6.0 => crate::fl!("uv-moderate"),
+        u if u < 8.0 => crate::fl!("uv-high"),
+        u if u < 11.0 => crate::fl!("uv-very-high"),
+        _ => crate::fl!("uv-extreme"),
+    }
+}
+
+/// Computes a 0-100 comfort score from temperature, humidity and wind.
+/// Ideal conditions are 21°C, humidity at or below 60% and calm wind.
+pub fn comfort_index(temp_c: f32, humidity: i32, windspeed_kmh: f32) -> f32 {
+    let humidity_excess = (humidity - 60).max(0) as f32;
+    (100.0 - (temp_c - 21.0).abs() * 3.0 - humidity_excess * 0.5 - windspeed_kmh * 0.3)
+        .clamp(0.0, 100.0)
+}
+
+/// Converts a comfort score to a localized description
+pub fn comfort_label(score: f32) -> String {
+    match score {
+        s if s >= 80.0 => crate::fl!("comfort-excellent"),
+        s if s >= 60.0 => crate::fl!("comfort-good"),
+        s if s >= 40.0 => crate::fl!("comfort-fair"),
+        s if s >= 20.0 => crate::fl!("comfort-uncomfortable"),
+        _ => crate::fl!("comfort-very-uncomfortable"),
+    }
+}
+
+/// Returns an emoji indicator for a comfort score
+pub fn comfort_emoji(score: f32) -> &'static str {
+    match score {
+        s if s >= 80.0 => "😄",
+        s if s >= 60.0 => "🙂",
+        s if s >= 40.0 => "😐",
+        s if s >= 20.0 => "😕",
+        _ => "😫",
+    }
+}
+
+/// Converts US AQI value to a localized description
+pub fn us_aqi_to_description(aqi: i32) -> String {
     match aqi {
-        0..=50 => "Good",
-        51..=100 => "Moderate",
-        101..=150 => "Unhealthy for Sensitive Groups",
-        151..=200 => "Unhealthy",
-        201..=300 => "Very Unhealthy",
-        _ => "Hazardous",
+        0..=50 => crate::fl!("aqi-good"),
+        51..=100 => crate::fl!("aqi-moderate"),
+        101..=150 => crate::fl!("aqi-unhealthy-sensitive"),
+        151..=200 => crate::fl!("aqi-unhealthy"),
+        201..=300 => crate::fl!("aqi-very-unhealthy"),
+        _ => crate::fl!("aqi-hazardous"),
     }
 }
 
-/// Converts European AQI value to description
-pub fn eu_aqi_to_description(aqi: i32) -> &'static str {
+/// Converts European AQI value to a localized description
+pub fn eu_aqi_to_description(aqi: i32) -> String {
     match aqi {
-        0..=20 => "Good",
-        21..=40 => "Fair",
-        41..=60 => "Moderate",
-        61..=80 => "Poor",
-        81..=100 => "Very Poor",
-        _ => "Extremely Poor",
+        0..=20 => crate::fl!("aqi-good"),
+        21..=40 => crate::fl!("aqi-fair"),
+        41..=60 => crate::fl!("aqi-moderate"),
+        61..=80 => crate::fl!("aqi-poor"),
+        81..=100 => crate::fl!("aqi-very-poor"),
+        _ => crate::fl!("aqi-extremely-poor"),
+    }
+}
+
+/// Converts a Canadian AQHI value to its localized health risk category
+pub fn aqhi_to_description(aqhi: i32) -> String {
+    match aqhi {
+        i32::MIN..=3 => crate::fl!("aqhi-low"),
+        4..=6 => crate::fl!("aqhi-moderate"),
+        7..=10 => crate::fl!("aqhi-high"),
+        _ => crate::fl!("aqhi-very-high"),
+    }
+}
+
+/// Official US EPA colour for an AQI value
+pub fn us_aqi_color(aqi: i32) -> cosmic::iced::Color {
+    let (r, g, b) = match aqi {
+        i32::MIN..=50 => (0, 228, 0),
+        51..=100 => (255, 255, 0),
+        101..=150 => (255, 126, 0),
+        151..=200 => (255, 0, 0),
+        201..=300 => (143, 63, 151),
+        _ => (126, 0, 35),
+    };
+    cosmic::iced::Color::from_rgb8(r, g, b)
+}
+
+/// Official European Environment Agency colour for a European AQI value
+pub fn eu_aqi_color(aqi: i32) -> cosmic::iced::Color {
+    let (r, g, b) = match aqi {
+        i32::MIN..=20 => (80, 240, 230),
+        21..=40 => (80, 204, 170),
+        41..=60 => (240, 230, 65),
+        61..=80 => (255, 80, 80),
+        81..=100 => (150, 0, 50),
+        _ => (125, 33, 129),
+    };
+    cosmic::iced::Color::from_rgb8(r, g, b)
+}
+
+/// Returns the AQI colour based on standard; AQHI uses the US palette by risk category
+pub fn aqi_color(aqi: i32, standard: AqiStandard) -> cosmic::iced::Color {
+    match standard {
+        AqiStandard::Us => us_aqi_color(aqi),
+        AqiStandard::European => eu_aqi_color(aqi),
+        AqiStandard::Canada => us_aqi_color(match aqi {
+            i32::MIN..=3 => 0,
+            4..=6 => 51,
+            7..=10 => 101,
+            _ => 151,
+        }),
     }
 }
 
-/// Returns AQI description based on standard
-pub fn aqi_to_description(aqi: i32, standard: AqiStandard) -> &'static str {
+/// Returns localized AQI description based on standard
+pub fn aqi_to_description(aqi: i32, standard: AqiStandard) -> String {
     match standard {
         AqiStandard::Us => us_aqi_to_description(aqi),
         AqiStandard::European => eu_aqi_to_description(aqi),
+        AqiStandard::Canada => aqhi_to_description(aqi),
     }
 }
 
-/// Returns label for the AQI standard
-pub fn aqi_standard_label(standard: AqiStandard) -> &'static str {
+/// Returns localized label for the AQI standard
+pub fn aqi_standard_label(standard: AqiStandard) -> String {
     match standard {
-        AqiStandard::Us => "US AQI",
-        AqiStandard::European => "EU AQI",
+        AqiStandard::Us => crate::fl!("aqi-standard-us"),
+        AqiStandard::European => crate::fl!("aqi-standard-eu"),
+        AqiStandard::Canada => crate::fl!("aqi-standard-ca"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn us_bounds_western_continental() {
+        assert!(is_us_bounds(47.6062, -122.3321), "Seattle");
+        assert!(is_us_bounds(34.0522, -118.2437), "Los Angeles");
+        assert!(is_us_bounds(32.7157, -117.1611), "San Diego");
+        assert!(is_us_bounds(39.7392, -104.9903), "Denver");
+        assert!(is_us_bounds(29.7604, -95.3698), "Houston");
+        assert!(is_us_bounds(47.9253, -97.0329), "Grand Forks");
+        assert!(
+            is_us_bounds(48.9937, -122.7471),
+            "Blaine, just south of 49N"
+        );
+    }
+
+    #[test]
+    fn us_bounds_midwest_and_great_lakes() {
+        assert!(is_us_bounds(41.8781, -87.6298), "Chicago");
+        assert!(is_us_bounds(44.9778, -93.2650), "Minneapolis");
+        assert!(is_us_bounds(33.7490, -84.3880), "Atlanta");
+        assert!(is_us_bounds(42.3314, -83.0458), "Detroit");
+        assert!(is_us_bounds(42.8864, -78.8784), "Buffalo");
+    }
+
+    #[test]
+    fn us_bounds_east_coast() {
+        assert!(is_us_bounds(40.7128, -74.0060), "New York City");
+        assert!(is_us_bounds(42.3601, -71.0589), "Boston");
+        assert!(is_us_bounds(44.4759, -73.2121), "Burlington");
+        assert!(is_us_bounds(44.8012, -68.7778), "Bangor");
+        assert!(is_us_bounds(44.9062, -66.9900), "Eastport");
+        assert!(is_us_bounds(25.7617, -80.1918), "Miami");
+        assert!(is_us_bounds(24.5551, -81.7800), "Key West");
+    }
+
+    #[test]
+    fn us_bounds_alaska_and_hawaii() {
+        assert!(is_us_bounds(61.2181, -149.9003), "Anchorage");
+        assert!(is_us_bounds(64.8378, -147.7164), "Fairbanks");
+        assert!(is_us_bounds(58.3019, -134.4197), "Juneau");
+        assert!(is_us_bounds(21.3069, -157.8583), "Honolulu");
+        assert!(is_us_bounds(19.7074, -155.0900), "Hilo");
+    }
+
+    #[test]
+    fn us_bounds_excludes_canada_near_border() {
+        assert!(!is_us_bounds(49.2827, -123.1207), "Vancouver");
+        assert!(!is_us_bounds(51.0447, -114.0719), "Calgary");
+        assert!(!is_us_bounds(49.8951, -97.1384), "Winnipeg");
+        assert!(!is_us_bounds(48.3809, -89.2477), "Thunder Bay");
+        assert!(!is_us_bounds(46.5219, -84.3461), "Sault Ste. Marie, ON");
+        assert!(!is_us_bounds(43.6532, -79.3832), "Toronto");
+        assert!(!is_us_bounds(43.0896, -79.0849), "Niagara Falls, ON");
+        assert!(!is_us_bounds(45.4215, -75.6972), "Ottawa");
+        assert!(!is_us_bounds(45.5017, -73.5673), "Montreal");
+        assert!(!is_us_bounds(46.8139, -71.2080), "Quebec City");
+        assert!(!is_us_bounds(44.6488, -63.5752), "Halifax");
+    }
+
+    #[test]
+    fn us_bounds_excludes_mexico_and_caribbean() {
+        assert!(!is_us_bounds(19.4326, -99.1332), "Mexico City");
+        assert!(!is_us_bounds(20.6597, -103.3496), "Guadalajara");
+        assert!(!is_us_bounds(21.1619, -86.8515), "Cancun");
+        assert!(!is_us_bounds(23.1136, -82.3666), "Havana");
+    }
+
+    #[test]
+    fn us_bounds_excludes_ocean_and_elsewhere() {
+        assert!(!is_us_bounds(35.0, -50.0), "mid-Atlantic");
+        assert!(!is_us_bounds(35.0, -140.0), "eastern Pacific");
+        assert!(!is_us_bounds(40.0, -160.0), "north of Hawaii");
+        assert!(!is_us_bounds(25.0, -157.0), "between Hawaii and Midway");
+        assert!(!is_us_bounds(32.3078, -64.7505), "Bermuda");
+        assert!(!is_us_bounds(51.5074, -0.1278), "London");
+    }
+
+    #[test]
+    fn europe_region_excludes_russia() {
+        assert_eq!(detect_region(55.7558, 37.6173), Region::Unknown, "Moscow");
+        assert_eq!(
+            detect_region(59.9343, 30.3351),
+            Region::Unknown,
+            "St. Petersburg"
+        );
+        assert_eq!(
+            detect_region(54.7104, 20.4522),
+            Region::Unknown,
+            "Kaliningrad"
+        );
+    }
+
+    #[test]
+    fn europe_region_includes_border_cities() {
+        assert_eq!(detect_region(41.0082, 28.9784), Region::Europe, "Istanbul");
+        assert_eq!(detect_region(60.1699, 24.9384), Region::Europe, "Helsinki");
+        assert_eq!(detect_region(59.4370, 24.7536), Region::Europe, "Tallinn");
+        assert_eq!(detect_region(54.6872, 25.2797), Region::Europe, "Vilnius");
+    }
+
+    #[test]
+    fn europe_region_excludes_middle_east_and_central_asia() {
+        assert_eq!(detect_region(39.9334, 32.8597), Region::Unknown, "Ankara");
+        assert_eq!(detect_region(41.7151, 44.8271), Region::Unknown, "Tbilisi");
+        assert_eq!(detect_region(43.2220, 76.8512), Region::Unknown, "Almaty");
+    }
+
+    #[test]
+    fn india_region_includes_major_cities() {
+        assert_eq!(detect_region(28.6139, 77.2090), Region::Asia, "Delhi");
+        assert_eq!(detect_region(19.0760, 72.8777), Region::Asia, "Mumbai");
+        assert_eq!(detect_region(22.5726, 88.3639), Region::Asia, "Kolkata");
+        assert_eq!(detect_region(13.0827, 80.2707), Region::Asia, "Chennai");
+        assert_eq!(detect_region(31.6340, 74.8723), Region::Asia, "Amritsar");
+        assert_eq!(detect_region(34.0837, 74.7973), Region::Asia, "Srinagar");
+        assert_eq!(detect_region(26.1445, 91.7362), Region::Asia, "Guwahati");
+        assert_eq!(detect_region(26.7271, 88.3953), Region::Asia, "Siliguri");
+    }
+
+    #[test]
+    fn india_region_excludes_neighbouring_countries() {
+        assert_eq!(detect_region(31.5497, 74.3436), Region::Unknown, "Lahore");
+        assert_eq!(
+            detect_region(33.6844, 73.0479),
+            Region::Unknown,
+            "Islamabad"
+        );
+        assert_eq!(detect_region(24.8607, 67.0011), Region::Unknown, "Karachi");
+        assert_eq!(
+            detect_region(27.7172, 85.3240),
+            Region::Unknown,
+            "Kathmandu"
+        );
+        assert_eq!(detect_region(23.8103, 90.4125), Region::Unknown, "Dhaka");
+        assert_eq!(detect_region(27.4728, 89.6390), Region::Unknown, "Thimphu");
+        assert_eq!(detect_region(6.9271, 79.8612), Region::Unknown, "Colombo");
+        assert_eq!(detect_region(29.6520, 91.1721), Region::Unknown, "Lhasa");
+    }
+
+    fn assert_close(actual: f32, expected: f32, label: &str) {
+        assert!(
+            (actual - expected).abs() < 0.1,
+            "{}: expected {:.2}, got {:.2}",
+            label,
+            expected,
+            actual
+        );
+    }
+
+    #[test]
+    fn magnus_dewpoint_saturated_equals_temperature() {
+        assert_close(magnus_dewpoint(0.0, 100), 0.0, "0°C at 100%");
+        assert_close(magnus_dewpoint(20.0, 100), 20.0, "20°C at 100%");
+        assert_close(magnus_dewpoint(-15.0, 100), -15.0, "-15°C at 100%");
+    }
+
+    #[test]
+    fn magnus_dewpoint_intermediate_humidity() {
+        assert_close(magnus_dewpoint(20.0, 50), 9.26, "20°C at 50%");
+        assert_close(magnus_dewpoint(25.0, 60), 16.70, "25°C at 60%");
+        assert_close(magnus_dewpoint(30.0, 80), 26.17, "30°C at 80%");
+        assert_close(magnus_dewpoint(-10.0, 70), -14.44, "-10°C at 70%");
+    }
+
+    #[test]
+    fn magnus_dewpoint_zero_humidity_is_finite() {
+        let dewpoint = magnus_dewpoint(20.0, 0);
+        assert!(dewpoint.is_finite());
+        assert_close(dewpoint, magnus_dewpoint(20.0, 1), "0% clamps to 1%");
+        assert!(dewpoint < -30.0);
+    }
+
+    #[test]
+    fn magnus_dewpoint_rises_with_humidity() {
+        let mut previous = f32::MIN;
+        for humidity in (10..=100).step_by(10) {
+            let dewpoint = magnus_dewpoint(22.0, humidity);
+            assert!(dewpoint > previous, "{}%", humidity);
+            assert!(dewpoint <= 22.0 + 0.01, "{}%", humidity);
+            previous = dewpoint;
+        }
+    }
+
+    #[test]
+    fn test_weathercode_to_icon_name() {
+        let cases = [
+            (3, "weather-overcast"),
+            (45, "weather-fog"),
+            (48, "weather-fog"),
+            (51, "weather-showers-scattered"),
+            (53, "weather-showers-scattered"),
+            (55, "weather-showers-scattered"),
+            (61, "weather-showers"),
+            (63, "weather-showers"),
+            (65, "weather-showers"),
+            (71, "weather-snow"),
+            (73, "weather-snow"),
+            (75, "weather-snow"),
+            (77, "weather-snow"),
+            (80, "weather-showers"),
+            (81, "weather-showers"),
+            (82, "weather-showers"),
+            (85, "weather-snow"),
+            (86, "weather-snow"),
+            (95, "weather-storm"),
+            (96, "weather-storm"),
+            (99, "weather-storm"),
+            (42, "weather-severe-alert"),
+        ];
+        for (code, expected) in cases {
+            // Only clear and partly cloudy codes have night variants
+            assert_eq!(
+                weathercode_to_icon_name(code, false),
+                expected,
+                "code {}",
+                code
+            );
+            assert_eq!(
+                weathercode_to_icon_name(code, true),
+                expected,
+                "code {} at night",
+                code
+            );
+        }
+
+        assert_eq!(weathercode_to_icon_name(0, false), "weather-clear");
+        assert_eq!(weathercode_to_icon_name(0, true), "weather-clear-night");
+        assert_eq!(weathercode_to_icon_name(1, false), "weather-few-clouds");
+        assert_eq!(
+            weathercode_to_icon_name(1, true),
+            "weather-few-clouds-night"
+        );
+        assert_eq!(weathercode_to_icon_name(2, false), "weather-few-clouds");
+        assert_eq!(
+            weathercode_to_icon_name(2, true),
+            "weather-few-clouds-night"
+        );
+
+        for code in [0, 1, 2, 3, 45, 61, 71, 95, 42] {
+            assert!(
+                !weathercode_to_icon_name(code, false).contains("night"),
+                "code {} by day",
+                code
+            );
+            assert_eq!(
+                weathercode_to_icon_name(code, true).contains("night"),
+                matches!(code, 0..=2),
+                "code {} at night",
+                code
+            );
+        }
+    }
+
+    #[test]
+    fn us_bounds_border_cities() {
+        assert!(!is_us_bounds(43.6532, -79.3832), "Toronto");
+        assert!(is_us_bounds(42.3314, -83.0458), "Detroit");
+        assert!(is_us_bounds(47.6062, -122.3321), "Seattle");
+        assert!(!is_us_bounds(49.2827, -123.1207), "Vancouver");
+        assert!(is_us_bounds(31.7619, -106.4850), "El Paso");
+        assert!(!is_us_bounds(32.5149, -117.0382), "Tijuana");
+        assert!(is_us_bounds(21.3069, -157.8583), "Honolulu");
+        assert!(is_us_bounds(58.3019, -134.4197), "Juneau");
+        assert!(!is_us_bounds(60.7212, -135.0568), "Whitehorse");
+        assert!(is_us_bounds(25.7617, -80.1918), "Miami");
+    }
+
+    #[test]
+    fn us_bounds_montana_border_at_49n() {
+        assert!(is_us_bounds(49.0, -110.0), "exactly on 49N");
+        assert!(is_us_bounds(48.99, -110.0), "just south of 49N");
+        assert!(!is_us_bounds(49.01, -110.0), "just north of 49N");
+    }
+
+    #[test]
+    fn wind_direction_to_compass_boundaries() {
+        let cases = [
+            (0, "N"),
+            (22, "N"),
+            (23, "NE"),
+            (67, "NE"),
+            (68, "E"),
+            (112, "E"),
+            (113, "SE"),
+            (157, "SE"),
+            (158, "S"),
+            (202, "S"),
+            (203, "SW"),
+            (247, "SW"),
+            (248, "W"),
+            (292, "W"),
+            (293, "NW"),
+            (337, "NW"),
+            (338, "N"),
+            (360, "N"),
+        ];
+        for (degrees, expected) in cases {
+            assert_eq!(
+                wind_direction_to_compass(degrees),
+                expected,
+                "{} degrees",
+                degrees
+            );
+        }
+    }
+
+    #[test]
+    fn wind_direction_to_compass_out_of_range_falls_back_to_north() {
+        assert_eq!(wind_direction_to_compass(400), "N");
+        assert_eq!(wind_direction_to_compass(-10), "N");
+    }
+
+    #[test]
+    fn test_new_york_is_us() {
+        assert_eq!(detect_region(40.7128, -74.0060), Region::Us);
+    }
+
+    #[test]
+    fn test_toronto_is_canada() {
+        assert_eq!(detect_region(43.6532, -79.3832), Region::Canada);
+    }
+
+    #[test]
+    fn test_berlin_is_europe() {
+        assert_eq!(detect_region(52.5200, 13.4050), Region::Europe);
+    }
+
+    // Sydney resolves to Australia now that BOM alerts are supported
+    #[test]
+    fn test_sydney_is_australia() {
+        assert_eq!(detect_region(-33.8688, 151.2093), Region::Australia);
+    }
+
+    #[test]
+    fn test_tokyo_is_unknown() {
+        assert_eq!(detect_region(35.6762, 139.6503), Region::Unknown);
+    }
+
+    #[test]
+    fn test_alaska_is_us() {
+        assert_eq!(detect_region(61.2181, -149.9003), Region::Us);
+    }
+
+    #[test]
+    fn test_hawaii_is_us() {
+        assert_eq!(detect_region(21.3069, -157.8583), Region::Us);
+    }
+
+    #[test]
+    fn test_paris_is_europe() {
+        assert_eq!(detect_region(48.8566, 2.3522), Region::Europe);
+    }
+
+    #[test]
+    fn test_vancouver_is_canada() {
+        assert_eq!(detect_region(49.2827, -123.1207), Region::Canada);
+    }
+
+    #[test]
+    fn test_montreal_is_canada() {
+        assert_eq!(detect_region(45.5017, -73.5673), Region::Canada);
+    }
+
+    #[test]
+    fn format_hour_parses_rfc3339_with_offset() {
+        assert_eq!(
+            format_hour("2025-01-20T09:00:00-05:00", TimeFormat::TwelveHour),
+            "9:00 AM"
+        );
+        assert_eq!(
+            format_hour("2025-01-20T14:00:00Z", TimeFormat::TwelveHour),
+            "2:00 PM"
+        );
+        assert_eq!(
+            format_hour("2025-01-20T10:00:00+01:00", TimeFormat::TwelveHour),
+            "10:00 AM"
+        );
+    }
+
+    #[test]
+    fn format_hour_falls_back_without_offset() {
+        assert_eq!(
+            format_hour("2025-01-20T00:00", TimeFormat::TwelveHour),
+            "12:00 AM"
+        );
+        assert_eq!(
+            format_hour("2025-01-20T12:00", TimeFormat::TwelveHour),
+            "12:00 PM"
+        );
+        // Only the hour is kept on the fallback path
+        assert_eq!(
+            format_hour("2025-01-20T13:30", TimeFormat::TwelveHour),
+            "1:00 PM"
+        );
+        assert_eq!(
+            format_hour("2025-01-20T09:00:00", TimeFormat::TwelveHour),
+            "9:00 AM"
+        );
+    }
+
+    #[test]
+    fn format_hour_returns_malformed_input_unchanged() {
+        assert_eq!(
+            format_hour("not a time", TimeFormat::TwelveHour),
+            "not a time"
+        );
+        assert_eq!(
+            format_hour("2025-01-20Tnoon", TimeFormat::TwelveHour),
+            "2025-01-20Tnoon"
+        );
+        assert_eq!(format_hour("", TimeFormat::TwelveHour), "");
+    }
+
+    #[test]
+    fn format_time_parses_rfc3339_with_offset() {
+        assert_eq!(
+            format_time("2025-01-20T06:30:00-05:00", TimeFormat::TwelveHour),
+            "6:30 AM"
+        );
+        assert_eq!(
+            format_time("2025-01-20T17:45:00Z", TimeFormat::TwelveHour),
+            "5:45 PM"
+        );
+    }
+
+    #[test]
+    fn format_time_falls_back_without_offset() {
+        assert_eq!(
+            format_time("2025-01-20T00:00", TimeFormat::TwelveHour),
+            "12:00 AM"
+        );
+        assert_eq!(
+            format_time("2025-01-20T12:00", TimeFormat::TwelveHour),
+            "12:00 PM"
+        );
+        assert_eq!(
+            format_time("2025-01-20T13:30", TimeFormat::TwelveHour),
+            "1:30 PM"
+        );
+        assert_eq!(
+            format_time("2025-01-20T09:00:00", TimeFormat::TwelveHour),
+            "9:00 AM"
+        );
+        assert_eq!(
+            format_time("2025-01-20T06:05", TimeFormat::TwelveHour),
+            "6:05 AM"
+        );
+    }
+
+    #[test]
+    fn format_time_returns_malformed_input_unchanged() {
+        assert_eq!(
+            format_time("not a time", TimeFormat::TwelveHour),
+            "not a time"
+        );
+        assert_eq!(
+            format_time("2025-01-20T14", TimeFormat::TwelveHour),
+            "2025-01-20T14"
+        );
+        assert_eq!(format_time("", TimeFormat::TwelveHour), "");
+    }
+
+    /// Builds a "lat,lon lat,lon ..." polygon string for an axis-aligned rectangle.
+    fn rectangle_polygon(south: f64, west: f64, north: f64, east: f64) -> String {
+        format!(
+            "{},{} {},{} {},{} {},{}",
+            south, west, north, west, north, east, south, east
+        )
+    }
+
+    proptest::proptest! {
+        #[test]
+        fn point_in_polygon_rectangle_contains_center(
+            south in -80.0f64..80.0,
+            west in -170.0f64..170.0,
+            height in 0.1f64..10.0,
+            width in 0.1f64..10.0,
+        ) {
+            let (north, east) = (south + height, west + width);
+            let polygon = rectangle_polygon(south, west, north, east);
+            let (center_lat, center_lon) = (south + height / 2.0, west + width / 2.0);
+
+            proptest::prop_assert!(point_in_polygon(center_lat, center_lon, &polygon));
+
+            let (dlat, dlon) = (height / 2.0, width / 2.0);
+            for (lat, lon) in [
+                (north + dlat, east + dlon),
+                (north + dlat, west - dlon),
+                (south - dlat, east + dlon),
+                (south - dlat, west - dlon),
+                (north + dlat, center_lon),
+                (south - dlat, center_lon),
+                (center_lat, east + dlon),
+                (center_lat, west - dlon),
+            ] {
+                proptest::prop_assert!(!point_in_polygon(lat, lon, &polygon));
+            }
+        }
+    }
+
+    #[test]
+    fn point_in_polygon_saskatchewan_outline() {
+        // Simplified outline with the slanted eastern border
+        let saskatchewan = "49.0,-110.0 60.0,-110.0 60.0,-102.0 55.8,-101.9 49.0,-101.4";
+        assert!(
+            point_in_polygon(52.1332, -106.6700, saskatchewan),
+            "Saskatoon"
+        );
+        assert!(point_in_polygon(50.4452, -104.6189, saskatchewan), "Regina");
+        assert!(
+            !point_in_polygon(49.8951, -97.1384, saskatchewan),
+            "Winnipeg"
+        );
+        assert!(
+            !point_in_polygon(53.5461, -113.4938, saskatchewan),
+            "Edmonton"
+        );
+        assert!(
+            !point_in_polygon(48.2325, -101.2963, saskatchewan),
+            "Minot, ND"
+        );
+    }
+
+    #[test]
+    fn point_in_polygon_concave_outline() {
+        // L-shape with the north-east quadrant cut out
+        let polygon = "0,0 10,0 10,5 5,5 5,10 0,10";
+        assert!(point_in_polygon(2.0, 8.0, polygon), "lower arm");
+        assert!(point_in_polygon(8.0, 2.0, polygon), "upper arm");
+        assert!(!point_in_polygon(8.0, 8.0, polygon), "cut-out corner");
+    }
+
+    #[test]
+    fn point_in_polygon_edges_are_half_open() {
+        // Ray casting counts the western edge as inside and the eastern edge as
+        // outside, so neighbouring polygons never both claim a shared border
+        let polygon = rectangle_polygon(0.0, 0.0, 10.0, 10.0);
+        assert!(point_in_polygon(5.0, 0.0, &polygon), "western edge");
+        assert!(!point_in_polygon(5.0, 10.0, &polygon), "eastern edge");
+    }
+
+    #[test]
+    fn point_in_polygon_degenerate_triangle_contains_nothing() {
+        let collinear = "0,0 5,5 10,10";
+        assert!(!point_in_polygon(5.0, 5.0, collinear));
+        assert!(!point_in_polygon(3.0, 1.0, collinear));
+    }
+
+    #[test]
+    fn point_in_polygon_empty_string_is_outside() {
+        assert!(!point_in_polygon(0.0, 0.0, ""));
+        assert!(!point_in_polygon(0.0, 0.0, "   "));
+    }
+
+    #[test]
+    fn point_in_polygon_skips_non_numeric_vertices() {
+        assert!(!point_in_polygon(5.0, 5.0, "a,b c,d e,f g,h"));
+        assert!(!point_in_polygon(5.0, 5.0, "x"));
+        // Garbage vertices are dropped, leaving the valid rectangle
+        let polygon = "0,0 junk 10,0 1,2,3 10,10 0,10 ,";
+        assert!(point_in_polygon(5.0, 5.0, polygon));
+    }
+
+    #[tokio::test]
+    async fn fetch_weather_parses_mocked_forecast() {
+        use wiremock::matchers::{header, method, path, query_param};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/v1/forecast"))
+            .and(query_param("latitude", "52.52"))
+            .and(query_param("longitude", "13.405"))
+            .and(query_param("temperature_unit", "celsius"))
+            .and(query_param("windspeed_unit", "kmh"))
+            .and(query_param("forecast_days", "2"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("ETag", "\"forecast-v1\"")
+                    .set_body_raw(
+                        include_str!("../tests/fixtures/open_meteo_forecast.json"),
+                        "application/json",
+                    ),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+        // Takes priority over the 200 once the client sends the ETag back
+        Mock::given(method("GET"))
+            .and(path("/v1/forecast"))
+            .and(header("If-None-Match", "\"forecast-v1\""))
+            .respond_with(ResponseTemplate::new(304))
+            .with_priority(1)
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        // The URL is passed in rather than set globally so parallel tests can't see it
+        let url = forecast_url(&server.uri(), 52.52, 13.405, "celsius", "kmh", 2);
+        let result = fetch_forecast(&url, None, "celsius", 6, None).await;
+        // A 304 reuses the stored response, cut to the new hourly length
+        let revalidated = fetch_forecast(&url, None, "celsius", 3, Some("\"forecast-v1\"")).await;
+        let (weather, etag) = match result.expect("mocked forecast should parse") {
+            Conditional::Modified(weather, etag) => (weather, etag),
+            Conditional::NotModified => panic!("first fetch has no ETag to match"),
+        };
+        assert_eq!(etag.as_deref(), Some("\"forecast-v1\""));
+        match revalidated.expect("304 should reuse the stored forecast") {
+            Conditional::Modified(weather, etag) => {
+                assert_eq!(etag.as_deref(), Some("\"forecast-v1\""));
+                assert_eq!(weather.hourly.len(), 3);
+                assert_eq!(weather.hourly[0].time, "2025-01-20T14:00");
+            }
+            Conditional::NotModified => panic!("stored forecast should be re-sliced"),
+        }
+
+        assert_eq!(weather.elevation, Some(38.0));
+
+        let current = &weather.current;
+        assert_eq!(current.temperature, 4.5);
+        assert_eq!(current.weathercode, 3);
+        assert_eq!(current.windspeed, 14.8);
+        assert_eq!(current.humidity, 81);
+        assert_eq!(current.feels_like, 0.9);
+        assert_eq!(current.dewpoint, 1.5);
+        assert_eq!(current.wind_direction, 250);
+        assert_eq!(current.wind_gusts, 31.3);
+        assert_eq!(current.uv_index, 0.6);
+        assert_eq!(current.visibility, 12000.0);
+        assert_eq!(current.pressure, 1009.7);
+        assert_eq!(current.cloud_cover, 100);
+        assert_eq!(current.precipitation, 0.1);
+        assert_eq!(current.rain, 0.1);
+        assert_eq!(current.showers, 0.0);
+        assert_eq!(current.snowfall, 0.0);
+
+        // Hourly starts at the current hour rather than midnight
+        assert_eq!(weather.hourly.len(), 6);
+        let first = &weather.hourly[0];
+        assert_eq!(first.time, "2025-01-20T14:00");
+        assert_eq!(first.temperature, 5.0);
+        assert_eq!(first.weathercode, 3);
+        assert_eq!(first.precipitation_probability, 28);
+        assert_eq!(first.precipitation, 0.0);
+        assert_eq!(first.humidity, 74);
+        assert_eq!(first.windspeed, 12.8);
+        assert_eq!(first.wind_direction, 210);
+        assert_eq!(first.direct_radiation, 120.0);
+        assert_eq!(first.diffuse_radiation, 60.0);
+        assert_eq!(weather.hourly[5].time, "2025-01-20T19:00");
+
+        assert_eq!(weather.forecast.len(), 2);
+        let today = &weather.forecast[0];
+        assert_eq!(today.date, "2025-01-20");
+        assert_eq!(today.temp_max, 5.1);
+        assert_eq!(today.temp_min, -1.4);
+        assert_eq!(today.apparent_temperature_max, 1.6);
+        assert_eq!(today.apparent_temperature_min, -5.2);
+        assert_eq!(today.precipitation_sum, 0.3);
+        assert_eq!(today.precipitation_probability_max, 35);
+        assert_eq!(today.uv_index_max, 0.9);
+        assert_eq!(today.weathercode, 3);
+        assert_eq!(today.sunrise, "2025-01-20T08:06");
+        assert_eq!(today.sunset, "2025-01-20T16:28");
+        assert_eq!(today.et0_evapotranspiration, 0.4);
+        assert_eq!(today.soil_temperature, Some(2.0));
+        assert_eq!(weather.forecast[1].soil_temperature, Some(4.0));
+        assert_eq!(today.solar_noon, "2025-01-20T12:17");
+        assert_eq!(today.snow_depth, None);
+
+        // Snow depth is the maximum of the day's hourly values
+        let tomorrow = &weather.forecast[1];
+        assert_eq!(tomorrow.weathercode, 73);
+        assert_eq!(tomorrow.snow_depth, Some(0.24));
+    }
+
+    #[test]
+    fn open_meteo_api_follows_config() {
+        let mut config = Config::default();
+        assert_eq!(weather_api_base(&config), "https://api.open-meteo.com");
+        assert_eq!(
+            OpenMeteoApi::air_quality(&config).base_url,
+            "https://air-quality-api.open-meteo.com"
+        );
+        assert_eq!(OpenMeteoApi::forecast(&config).api_key, None);
+
+        // A blank key stays on the free tier
+        config.open_meteo_api_key = Some("  ".to_string());
+        assert_eq!(weather_api_base(&config), "https://api.open-meteo.com");
+
+        config.open_meteo_api_key = Some(" secret ".to_string());
+        let marine = OpenMeteoApi::marine(&config);
+        assert_eq!(
+            marine.base_url,
+            "https://customer-marine-api.open-meteo.com"
+        );
+        assert_eq!(marine.api_key.as_deref(), Some("secret"));
+
+        // A self-hosted instance serves every API and keeps the key
+        config.open_meteo_base_url = Some(" http://localhost:8080/ ".to_string());
+        assert_eq!(weather_api_base(&config), "http://localhost:8080");
+        let air_quality = OpenMeteoApi::air_quality(&config);
+        assert_eq!(air_quality.base_url, "http://localhost:8080");
+        assert_eq!(air_quality.api_key.as_deref(), Some("secret"));
+    }
+
+    #[test]
+    fn format_date_time_follows_clock_setting() {
+        let datetime = chrono::DateTime::parse_from_rfc3339("2025-01-20T14:05:00+01:00").unwrap();
+        assert_eq!(
+            format_date_time(&datetime, TimeFormat::TwelveHour),
+            "Jan 20 2:05 PM"
+        );
+        assert_eq!(
+            format_date_time(&datetime, TimeFormat::TwentyFourHour),
+            "Jan 20 14:05"
+        );
+        let morning = chrono::DateTime::parse_from_rfc3339("2025-03-07T09:30:00Z").unwrap();
+        assert_eq!(
+            format_date_time(&morning, TimeFormat::TwelveHour),
+            "Mar 07 9:30 AM"
+        );
+        assert_eq!(
+            format_date_time(&morning, TimeFormat::TwentyFourHour),
+            "Mar 07 09:30"
+        );
+    }
+
+    #[test]
+    fn format_hour_and_time_use_24_hour_clock() {
+        let format = TimeFormat::TwentyFourHour;
+        assert_eq!(format_hour("2025-01-20T09:00:00-05:00", format), "09:00");
+        assert_eq!(format_hour("2025-01-20T00:00", format), "00:00");
+        assert_eq!(format_hour("2025-01-20T13:30", format), "13:00");
+        assert_eq!(format_time("2025-01-20T17:45:00Z", format), "17:45");
+        assert_eq!(format_time("2025-01-20T06:05", format), "06:05");
+        assert_eq!(format_time("not a time", format), "not a time");
+    }
+
+    #[test]
+    fn haversine_distance_between_cities() {
+        assert_eq!(
+            haversine_distance(40.7128, -74.0060, 40.7128, -74.0060),
+            0.0
+        );
+        // New York to Los Angeles is about 3936 km
+        let ny_la = haversine_distance(40.7128, -74.0060, 34.0522, -118.2437);
+        assert!((ny_la - 3936.0).abs() < 10.0, "{}", ny_la);
+        // London to Paris is about 344 km, and the result is symmetric
+        let london_paris = haversine_distance(51.5074, -0.1278, 48.8566, 2.3522);
+        assert!((london_paris - 344.0).abs() < 5.0, "{}", london_paris);
+        assert_eq!(
+            london_paris,
+            haversine_distance(48.8566, 2.3522, 51.5074, -0.1278)
+        );
+    }
+
+    #[test]
+    fn backoff_delay_doubles_then_caps() {
+        assert_eq!(backoff_delay_ms(500, 0, 0), 500);
+        assert_eq!(backoff_delay_ms(500, 1, 0), 1000);
+        assert_eq!(backoff_delay_ms(500, 3, 250), 4250);
+        assert_eq!(backoff_delay_ms(500, 10, 0), RETRY_MAX_DELAY_MS);
+        assert_eq!(backoff_delay_ms(500, 200, 0), RETRY_MAX_DELAY_MS);
+        assert_eq!(backoff_delay_ms(u64::MAX, 63, u64::MAX), RETRY_MAX_DELAY_MS);
+    }
+
+    #[test]
+    fn lightning_risk_label_thresholds() {
+        let cases = [
+            (0.0, "None"),
+            (-1.0, "None"),
+            (0.5, "Low"),
+            (1.99, "Low"),
+            (2.0, "Moderate"),
+            (4.99, "Moderate"),
+            (5.0, "High"),
+            (12.0, "High"),
+        ];
+        for (potential, expected) in cases {
+            // CAPE is ignored when the lightning potential is known
+            assert_eq!(
+                lightning_risk_label(Some(potential), 3000.0),
+                expected,
+                "{} J/kg",
+                potential
+            );
+        }
+    }
+
+    #[test]
+    fn lightning_risk_label_falls_back_to_cape() {
+        let cases = [
+            (0.0, "Low"),
+            (999.0, "Low"),
+            (1000.0, "Moderate"),
+            (2499.0, "Moderate"),
+            (2500.0, "High"),
+            (4000.0, "High"),
+        ];
+        for (cape, expected) in cases {
+            assert_eq!(lightning_risk_label(None, cape), expected, "CAPE {}", cape);
+        }
+    }
+
+    #[test]
+    fn test_fog_risk_spread_threshold() {
+        assert!(fog_risk(10.0, 9.0));
+        assert!(fog_risk(5.0, 5.0));
+        assert!(!fog_risk(10.0, 7.0));
+        assert!(!fog_risk(25.0, 10.0));
+    }
+
+    #[test]
+    fn test_needs_watering_when_et0_exceeds_rain() {
+        assert!(needs_watering(4.2, 0.0));
+        assert!(needs_watering(4.2, 3.0));
+        assert!(!needs_watering(2.0, 6.5));
+        assert!(!needs_watering(0.0, 0.0));
+    }
+
+    #[test]
+    fn beaufort_to_description_names_every_force() {
+        let cases = [
+            (0, "Calm"),
+            (1, "Light air"),
+            (2, "Light breeze"),
+            (3, "Gentle breeze"),
+            (4, "Moderate breeze"),
+            (5, "Fresh breeze"),
+            (6, "Strong breeze"),
+            (7, "Near gale"),
+            (8, "Gale"),
+            (9, "Strong gale"),
+            (10, "Storm"),
+            (11, "Violent storm"),
+            (12, "Hurricane force"),
+            (u8::MAX, "Hurricane force"),
+        ];
+        for (force, expected) in cases {
+            assert_eq!(beaufort_to_description(force), expected, "force {}", force);
+        }
+    }
+
+    #[test]
+    fn fosberg_index_known_values() {
+        // (temp °C, humidity %, wind km/h, index); covers all three moisture branches
+        let cases = [
+            (25.0, 40, 10.0, 12.290),
+            (10.0, 90, 30.0, 10.070),
+            (30.0, 5, 10.0, 19.352),
+            (25.0, 60, 16.1, 15.501),
+            (30.0, 25, 25.0, 36.719),
+            (35.0, 8, 35.0, 64.020),
+            (38.0, 6, 45.0, 84.975),
+        ];
+        for (temp_c, humidity, wind_kmh, expected) in cases {
+            assert_close(
+                fosberg_index(temp_c, humidity, wind_kmh),
+                expected,
+                &format!("{}°C, {}%, {} km/h", temp_c, humidity, wind_kmh),
+            );
+        }
+        // Capped at 100 in extreme heat, drought and wind
+        assert_eq!(fosberg_index(40.0, 5, 60.0), 100.0);
+    }
+
+    #[test]
+    fn fire_weather_index_levels() {
+        assert_eq!(fire_weather_index(25.0, 40, 10.0), "Low");
+        assert_eq!(fire_weather_index(30.0, 5, 10.0), "Moderate");
+        assert_eq!(fire_weather_index(30.0, 25, 25.0), "High");
+        assert_eq!(fire_weather_index(35.0, 8, 35.0), "Very High");
+        assert_eq!(fire_weather_index(38.0, 6, 45.0), "Extreme");
+    }
+
+    #[test]
+    fn uv_index_to_category_bands() {
+        let cases = [
+            (0.0, "Low"),
+            (2.9, "Low"),
+            (3.0, "Moderate"),
+            (5.9, "Moderate"),
+            (6.0, "High"),
+            (7.9, "High"),
+            (8.0, "Very High"),
+            (10.9, "Very High"),
+            (11.0, "Extreme"),
+        ];
+        for (uv, expected) in cases {
+            assert_eq!(uv_index_to_category(uv), expected, "UV {}", uv);
+        }
+    }
+
+    #[test]
+    fn comfort_label_bands() {
+        let cases = [
+            (100.0, "Excellent"),
+            (80.0, "Excellent"),
+            (79.9, "Good"),
+            (60.0, "Good"),
+            (40.0, "Fair"),
+            (20.0, "Uncomfortable"),
+            (19.9, "Very Uncomfortable"),
+            (0.0, "Very Uncomfortable"),
+        ];
+        for (score, expected) in cases {
+            assert_eq!(comfort_label(score), expected, "score {}", score);
+        }
+    }
+
+    #[test]
+    fn moon_phase_name_covers_the_cycle() {
+        let cases = [
+            (0.0, "New Moon"),
+            (0.1, "Waxing Crescent"),
+            (0.25, "First Quarter"),
+            (0.4, "Waxing Gibbous"),
+            (0.5, "Full Moon"),
+            (0.6, "Waning Gibbous"),
+            (0.75, "Last Quarter"),
+            (0.9, "Waning Crescent"),
+            (0.99, "New Moon"),
+        ];
+        for (phase, expected) in cases {
+            assert_eq!(moon_phase_name(phase), expected, "phase {}", phase);
+        }
     }
 }
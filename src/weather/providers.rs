@@ -0,0 +1,3 @@
+//! Alternative weather data sources that map onto the shared `WeatherData` types.
+
+pub mod weatherapi;
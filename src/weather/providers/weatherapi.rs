@@ -0,0 +1,458 @@
+//! WeatherAPI.com forecast provider.
+
+use serde::Deserialize;
+
+use crate::weather::{
+    http_client, magnus_dewpoint, max_retry_attempts, read_json, retry_with_backoff, solar_noon,
+    CurrentWeather, DailyForecast, HourlyForecast, WeatherData, WeatherError, RETRY_BASE_DELAY_MS,
+};
+
+/// WeatherAPI.com API host.
+pub const BASE_URL: &str = "https://api.weatherapi.com";
+
+/// WeatherAPI.com forecast response
+#[derive(Debug, Deserialize)]
+struct ForecastResponse {
+    location: Location,
+    current: Current,
+    forecast: Forecast,
+}
+
+#[derive(Debug, Deserialize)]
+struct Location {
+    /// Local time at the location ("2025-01-20 14:30")
+    localtime: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct Condition {
+    code: i32,
+}
+
+#[derive(Debug, Deserialize)]
+struct Current {
+    temp_c: f32,
+    temp_f: f32,
+    condition: Condition,
+    wind_kph: f32,
+    wind_degree: i32,
+    gust_kph: f32,
+    pressure_mb: f32,
+    precip_mm: f32,
+    humidity: i32,
+    cloud: i32,
+    feelslike_c: f32,
+    feelslike_f: f32,
+    dewpoint_c: Option<f32>,
+    vis_km: f32,
+    uv: f32,
+}
+
+#[derive(Debug, Deserialize)]
+struct Forecast {
+    forecastday: Vec<ForecastDay>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ForecastDay {
+    date: String,
+    day: Day,
+    astro: Astro,
+    hour: Vec<Hour>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Day {
+    maxtemp_c: f32,
+    maxtemp_f: f32,
+    mintemp_c: f32,
+    mintemp_f: f32,
+    totalprecip_mm: f32,
+    daily_chance_of_rain: i32,
+    daily_chance_of_snow: i32,
+    uv: f32,
+    condition: Condition,
+}
+
+#[derive(Debug, Deserialize)]
+struct Astro {
+    /// "07:12 AM"
+    sunrise: String,
+    sunset: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct Hour {
+    /// "2025-01-20 14:00"
+    time: String,
+    temp_c: f32,
+    temp_f: f32,
+    condition: Condition,
+    wind_kph: f32,
+    wind_degree: i32,
+    precip_mm: f32,
+    humidity: i32,
+    chance_of_rain: i32,
+    chance_of_snow: i32,
+}
+
+/// Fetches weather from WeatherAPI.com and maps it onto the shared `WeatherData`.
+/// Units take the same values as the Open-Meteo `temperature_unit`/`windspeed_unit` parameters.
+pub async fn fetch_weather(
+    latitude: f64,
+    longitude: f64,
+    key: &str,
+    temperature_unit: &str,
+    windspeed_unit: &str,
+    hourly_hours: usize,
+    forecast_days: u8,
+) -> Result<WeatherData, WeatherError> {
+    let data = fetch_forecast(BASE_URL, latitude, longitude, key, forecast_days).await?;
+    // Lower plans cap the forecast length and quietly return fewer days
+    let days = data.forecast.forecastday.len();
+    if days < usize::from(forecast_days) {
+        tracing::warn!(
+            "WeatherAPI.com returned {} of {} forecast days; the plan limits the forecast length",
+            days,
+            forecast_days
+        );
+    }
+    Ok(to_weather_data(
+        &data,
+        temperature_unit,
+        windspeed_unit,
+        hourly_hours,
+    ))
+}
+
+/// Requests the raw forecast from a WeatherAPI.com host.
+async fn fetch_forecast(
+    base_url: &str,
+    latitude: f64,
+    longitude: f64,
+    key: &str,
+    forecast_days: u8,
+) -> Result<ForecastResponse, WeatherError> {
+    let url = format!(
+        "{}/v1/forecast.json?q={},{}&days={}&aqi=no&alerts=no",
+        base_url, latitude, longitude, forecast_days
+    );
+    tracing::debug!("GET {}", url);
+
+    let url = &url;
+    retry_with_backoff(
+        || async move {
+            // Key goes in the query builder so it stays out of the debug log
+            let response = http_client().get(url).query(&[("key", key)]).send().await?;
+            read_json("WeatherAPI.com", response).await
+        },
+        max_retry_attempts(),
+        RETRY_BASE_DELAY_MS,
+    )
+    .await
+}
+
+/// Maps a WeatherAPI.com response onto the shared `WeatherData`.
+fn to_weather_data(
+    data: &ForecastResponse,
+    temperature_unit: &str,
+    windspeed_unit: &str,
+    hourly_hours: usize,
+) -> WeatherData {
+    let fahrenheit = temperature_unit == "fahrenheit";
+    let temp = |c: f32, f: f32| if fahrenheit { f } else { c };
+    let wind = |kph: f32| match windspeed_unit {
+        "mph" => kph / 1.609_34,
+        "ms" => kph / 3.6,
+        "kn" => kph / 1.852,
+        _ => kph,
+    };
+
+    let current = &data.current;
+    let dewpoint_c = current
+        .dewpoint_c
+        .unwrap_or_else(|| magnus_dewpoint(current.temp_c, current.humidity));
+    let current_weather = CurrentWeather {
+        temperature: temp(current.temp_c, current.temp_f),
+        weathercode: condition_to_wmo(current.condition.code),
+        windspeed: wind(current.wind_kph),
+        humidity: current.humidity,
+        feels_like: temp(current.feelslike_c, current.feelslike_f),
+        dewpoint: temp(dewpoint_c, dewpoint_c * 9.0 / 5.0 + 32.0),
+        wind_direction: current.wind_degree,
+        wind_gusts: wind(current.gust_kph),
+        uv_index: current.uv,
+        visibility: current.vis_km * 1000.0,
+        // WeatherAPI reports sea-level pressure only
+        pressure: current.pressure_mb,
+        cloud_cover: current.cloud,
+        precipitation: current.precip_mm,
+        rain: current.precip_mm,
+        showers: 0.0,
+        snowfall: 0.0,
+        // Convective parameters aren't available from WeatherAPI.com
+        cape: 0.0,
+        lightning_potential: None,
+    };
+
+    // Hours are listed from midnight of the first day; start at the current local hour
+    let current_hour = to_iso_time(data.location.localtime.get(..13).unwrap_or_default());
+    let hourly = data
+        .forecast
+        .forecastday
+        .iter()
+        .flat_map(|day| &day.hour)
+        .map(|hour| (to_iso_time(&hour.time), hour))
+        .filter(|(time, _)| time.as_str() >= current_hour.as_str())
+        .take(hourly_hours)
+        .map(|(time, hour)| HourlyForecast {
+            time,
+            temperature: temp(hour.temp_c, hour.temp_f),
+            weathercode: condition_to_wmo(hour.condition.code),
+            precipitation_probability: hour.chance_of_rain.max(hour.chance_of_snow),
+            precipitation: hour.precip_mm,
+            humidity: hour.humidity,
+            windspeed: wind(hour.wind_kph),
+            wind_direction: hour.wind_degree,
+            // Irradiance isn't available from WeatherAPI.com
+            direct_radiation: 0.0,
+            diffuse_radiation: 0.0,
+        })
+        .collect();
+
+    let forecast = data
+        .forecast
+        .forecastday
+        .iter()
+        .map(|day| {
+            let sunrise = to_local_timestamp(&day.date, &day.astro.sunrise);
+            let sunset = to_local_timestamp(&day.date, &day.astro.sunset);
+            DailyForecast {
+                date: day.date.clone(),
+                temp_max: temp(day.day.maxtemp_c, day.day.maxtemp_f),
+                temp_min: temp(day.day.mintemp_c, day.day.mintemp_f),
+                // No daily feels-like in this API
+                apparent_temperature_max: temp(day.day.maxtemp_c, day.day.maxtemp_f),
+                apparent_temperature_min: temp(day.day.mintemp_c, day.day.mintemp_f),
+                precipitation_sum: day.day.totalprecip_mm,
+                precipitation_probability_max: day
+                    .day
+                    .daily_chance_of_rain
+                    .max(day.day.daily_chance_of_snow),
+                uv_index_max: day.day.uv,
+                weathercode: condition_to_wmo(day.day.condition.code),
+                solar_noon: solar_noon(&sunrise, &sunset),
+                sunrise,
+                sunset,
+                snow_depth: None,
+                // Agricultural data isn't available from WeatherAPI.com
+                et0_evapotranspiration: 0.0,
+                soil_temperature: None,
+            }
+        })
+        .collect();
+
+    WeatherData {
+        current: current_weather,
+        hourly,
+        forecast,
+        // Not reported by WeatherAPI.com
+        elevation: None,
+    }
+}
+
+/// Converts "2025-01-20 14:00" to Open-Meteo's "2025-01-20T14:00".
+fn to_iso_time(time: &str) -> String {
+    time.replacen(' ', "T", 1)
+}
+
+/// Combines a date with a 12-hour astro time ("07:12 AM") into "2025-01-20T07:12".
+fn to_local_timestamp(date: &str, time: &str) -> String {
+    let time = chrono::NaiveTime::parse_from_str(time, "%I:%M %p")
+        .map(|t| t.format("%H:%M").to_string())
+        .unwrap_or_else(|_| time.to_string());
+    format!("{}T{}", date, time)
+}
+
+/// Maps WeatherAPI.com condition codes to the closest WMO weather code.
+fn condition_to_wmo(code: i32) -> i32 {
+    match code {
+        1000 => 0,
+        1003 => 2,
+        1006 | 1009 => 3,
+        1030 | 1135 => 45,
+        1147 => 48,
+        1063 | 1240 => 80,
+        1243 => 81,
+        1246 => 82,
+        1066 | 1249 | 1255 => 85,
+        1252 | 1258 => 86,
+        1072 | 1150 | 1153 => 51,
+        1168 | 1171 => 55,
+        1069 | 1204 | 1210 | 1213 => 71,
+        1114 | 1207 | 1216 | 1219 => 73,
+        1117 | 1222 | 1225 => 75,
+        1237 | 1261 | 1264 => 77,
+        1180 | 1183 | 1198 => 61,
+        1186 | 1189 => 63,
+        1192 | 1195 | 1201 => 65,
+        1087 | 1273 | 1276 | 1279 | 1282 => 95,
+        _ => 3,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn condition_to_wmo_maps_known_codes() {
+        let cases = [
+            (1000, 0),
+            (1003, 2),
+            (1009, 3),
+            (1135, 45),
+            (1147, 48),
+            (1063, 80),
+            (1246, 82),
+            (1153, 51),
+            (1171, 55),
+            (1183, 61),
+            (1189, 63),
+            (1195, 65),
+            (1210, 71),
+            (1219, 73),
+            (1225, 75),
+            (1237, 77),
+            (1255, 85),
+            (1258, 86),
+            (1276, 95),
+        ];
+        for (code, expected) in cases {
+            assert_eq!(condition_to_wmo(code), expected, "condition {}", code);
+        }
+    }
+
+    #[test]
+    fn condition_to_wmo_unknown_code_is_overcast() {
+        assert_eq!(condition_to_wmo(0), 3);
+        assert_eq!(condition_to_wmo(9999), 3);
+    }
+
+    #[test]
+    fn to_local_timestamp_converts_12_hour_times() {
+        assert_eq!(
+            to_local_timestamp("2025-01-20", "08:06 AM"),
+            "2025-01-20T08:06"
+        );
+        assert_eq!(
+            to_local_timestamp("2025-01-20", "04:28 PM"),
+            "2025-01-20T16:28"
+        );
+        assert_eq!(
+            to_local_timestamp("2025-01-20", "12:05 AM"),
+            "2025-01-20T00:05"
+        );
+    }
+
+    #[tokio::test]
+    async fn fetch_forecast_maps_mocked_response() {
+        use wiremock::matchers::{method, path, query_param};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/v1/forecast.json"))
+            .and(query_param("q", "52.52,13.405"))
+            .and(query_param("days", "2"))
+            .and(query_param("key", "test-key"))
+            .respond_with(ResponseTemplate::new(200).set_body_raw(
+                include_str!("../../../tests/fixtures/weatherapi_forecast.json"),
+                "application/json",
+            ))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let data = fetch_forecast(&server.uri(), 52.52, 13.405, "test-key", 2)
+            .await
+            .expect("mocked forecast should parse");
+        let weather = to_weather_data(&data, "celsius", "kmh", 6);
+
+        // Elevation isn't reported, so the stored value must be left alone
+        assert_eq!(weather.elevation, None);
+
+        let current = &weather.current;
+        assert_eq!(current.temperature, 4.5);
+        assert_eq!(current.weathercode, 3);
+        assert_eq!(current.windspeed, 14.8);
+        assert_eq!(current.humidity, 81);
+        assert_eq!(current.feels_like, 0.9);
+        assert_eq!(current.dewpoint, 1.5);
+        assert_eq!(current.wind_direction, 250);
+        assert_eq!(current.wind_gusts, 31.3);
+        assert_eq!(current.uv_index, 0.6);
+        assert_eq!(current.visibility, 12000.0);
+        assert_eq!(current.pressure, 1013.0);
+        assert_eq!(current.cloud_cover, 100);
+        assert_eq!(current.precipitation, 0.1);
+        assert_eq!(current.lightning_potential, None);
+
+        // Hourly starts at the current local hour rather than midnight
+        assert_eq!(weather.hourly.len(), 6);
+        let first = &weather.hourly[0];
+        assert_eq!(first.time, "2025-01-20T14:00");
+        assert_eq!(first.temperature, 5.2);
+        assert_eq!(first.weathercode, 61);
+        assert_eq!(first.precipitation_probability, 28);
+        assert_eq!(first.precipitation, 0.2);
+        assert_eq!(first.humidity, 66);
+        assert_eq!(first.windspeed, 17.0);
+        assert_eq!(first.wind_direction, 214);
+        assert_eq!(weather.hourly[5].time, "2025-01-20T19:00");
+
+        assert_eq!(weather.forecast.len(), 2);
+        let today = &weather.forecast[0];
+        assert_eq!(today.date, "2025-01-20");
+        assert_eq!(today.temp_max, 5.1);
+        assert_eq!(today.temp_min, -1.4);
+        assert_eq!(today.precipitation_sum, 0.3);
+        assert_eq!(today.precipitation_probability_max, 35);
+        assert_eq!(today.uv_index_max, 0.9);
+        assert_eq!(today.weathercode, 80);
+        assert_eq!(today.sunrise, "2025-01-20T08:06");
+        assert_eq!(today.sunset, "2025-01-20T16:28");
+        assert_eq!(today.solar_noon, "2025-01-20T12:17");
+
+        // Snow chance wins when it's higher than the rain chance
+        let tomorrow = &weather.forecast[1];
+        assert_eq!(tomorrow.precipitation_probability_max, 80);
+        assert_eq!(tomorrow.weathercode, 73);
+
+        // Hours carry over into the next day
+        let long = to_weather_data(&data, "celsius", "kmh", 48);
+        assert_eq!(long.hourly.len(), 34);
+        assert_eq!(long.hourly[10].time, "2025-01-21T00:00");
+    }
+
+    #[test]
+    fn to_weather_data_converts_units() {
+        let data: ForecastResponse = serde_json::from_str(include_str!(
+            "../../../tests/fixtures/weatherapi_forecast.json"
+        ))
+        .expect("fixture should parse");
+
+        let weather = to_weather_data(&data, "fahrenheit", "mph", 1);
+        assert_eq!(weather.current.temperature, 40.1);
+        assert_eq!(weather.current.feels_like, 33.6);
+        assert_eq!(weather.forecast[0].temp_max, 41.2);
+        assert_eq!(weather.hourly[0].temperature, 41.4);
+        assert!((weather.current.windspeed - 9.2).abs() < 0.05);
+        assert!((weather.current.dewpoint - 34.7).abs() < 0.05);
+
+        let weather = to_weather_data(&data, "celsius", "ms", 1);
+        assert!((weather.current.windspeed - 4.11).abs() < 0.01);
+        let weather = to_weather_data(&data, "celsius", "kn", 1);
+        assert!((weather.current.windspeed - 7.99).abs() < 0.01);
+    }
+}